@@ -31,3 +31,27 @@ mod value;
 pub use value::*;
 mod doctype;
 pub use doctype::*;
+mod err_boundary;
+pub use err_boundary::*;
+mod for_loop;
+pub use for_loop::*;
+mod template;
+pub use template::*;
+mod provide;
+pub use provide::*;
+mod global_listener;
+pub use global_listener::*;
+mod resource;
+pub use resource::*;
+mod with_block;
+pub use with_block::*;
+mod match_view;
+pub use match_view::*;
+mod live_region;
+pub use live_region::*;
+mod show_hidden;
+pub use show_hidden::*;
+mod auto_id;
+pub use auto_id::*;
+mod str_consts;
+pub use str_consts::*;
@@ -2,6 +2,7 @@ pub mod directive;
 pub mod kv;
 pub mod selector;
 pub mod spread_attrs;
+pub mod struct_attr;
 
 use syn::{
     ext::IdentExt,
@@ -9,7 +10,7 @@ use syn::{
     Token,
 };
 
-use self::{directive::Directive, kv::KvAttr, spread_attrs::SpreadAttr};
+use self::{directive::Directive, kv::KvAttr, spread_attrs::SpreadAttr, struct_attr::StructAttr};
 use crate::{error_ext::ResultExt, parse::rollback_err};
 
 #[derive(Clone)]
@@ -17,13 +18,25 @@ pub enum Attr {
     Kv(KvAttr),
     Directive(Directive),
     Spread(SpreadAttr),
+    Struct(StructAttr),
 }
 
 impl Parse for Attr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        // ident then colon must be directive
+        // ident then colon then braces may be a struct attribute
+        // otherwise, ident then colon must be directive
         // just ident must be regular kv attribute
         // otherwise, try kv or spread
+        if input.peek(syn::Ident::peek_any)
+            && input.peek2(Token![:])
+            && !input.peek2(Token![::])
+            && input.peek3(syn::token::Brace)
+        {
+            if let Some(s) = rollback_err(input, StructAttr::parse) {
+                return Ok(Self::Struct(s));
+            }
+        }
+
         if input.peek(syn::Ident::peek_any) && input.peek2(Token![:]) {
             // cannot be anything else, abort if fails
             let dir = Directive::parse(input).unwrap_or_abort();
@@ -52,6 +65,14 @@ impl std::ops::Deref for Attrs {
     fn deref(&self) -> &Self::Target { &self.0 }
 }
 
+impl std::ops::DerefMut for Attrs {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+}
+
+impl Attrs {
+    pub fn push(&mut self, attr: Attr) { self.0.push(attr); }
+}
+
 impl Parse for Attrs {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut vec = Vec::new();
@@ -102,4 +123,57 @@ mod tests {
         assert!(attrs[4].is_kv());
         assert!(attrs[5].is_spread());
     }
+
+    #[test]
+    fn struct_attr() {
+        let attrs: Attrs = parse_quote! {
+            config:{ a=1 {b} c }
+            class:{primary}
+            attr:{name}="value"
+        };
+        let Attr::Struct(config) = &attrs[0] else {
+            panic!("expected struct attribute");
+        };
+        assert_eq!(config.key().repr(), "config");
+        let fields = config
+            .fields()
+            .iter()
+            .map(|f| f.key().repr())
+            .collect::<Vec<_>>();
+        assert_eq!(fields, ["a", "b", "c"]);
+        assert!(matches!(attrs[1], Attr::Directive(..)));
+        assert!(matches!(attrs[2], Attr::Directive(..)));
+    }
+
+    #[test]
+    fn prop_shorthand_keeps_name() {
+        use crate::ast::{KebabIdentOrStr, Value};
+
+        let check = |attr: Attr, key: &str, var: &str| {
+            let Attr::Directive(dir) = attr else {
+                panic!("expected directive");
+            };
+            assert_eq!(dir.dir, "prop");
+            let KebabIdentOrStr::KebabIdent(ident) = dir.key else {
+                panic!("expected kebab ident key");
+            };
+            assert_eq!(ident.repr(), key);
+            let Some(Value::Block { tokens, .. }) = dir.value else {
+                panic!("expected block value");
+            };
+            assert_eq!(tokens.to_string(), var);
+        };
+
+        check(parse_quote! { prop:{value} }, "value", "r#value");
+        check(
+            parse_quote! { prop:{innerHTML} },
+            "innerHTML",
+            "r#innerHTML",
+        );
+        check(
+            parse_quote! { prop:{text-content} },
+            "text-content",
+            "r#text_content",
+        );
+    }
 }
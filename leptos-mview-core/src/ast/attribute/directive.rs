@@ -1,12 +1,16 @@
+use proc_macro2::{TokenStream, TokenTree};
+use proc_macro_error2::emit_error;
+use quote::quote_spanned;
 use syn::{
     ext::IdentExt,
     parse::{Parse, ParseStream},
     Token,
 };
 
+use super::kv::optional_value;
 use crate::{
     ast::{BracedKebabIdent, KebabIdentOrStr, Value},
-    parse::rollback_err,
+    parse::{self, rollback_err},
 };
 
 /// A special attribute like `on:click={...}`.
@@ -28,12 +32,40 @@ use crate::{
 /// button on:click:undelegated={on_click};
 /// ```
 /// `on:{click}:undelegated` also works for the shorthand.
+///
+/// The value of an `on:` directive can also be a tuple of an extractor and a
+/// setter, which sets the setter to the extracted value:
+/// ```ignore
+/// input on:input=(event_target_value, set_value);
+/// ```
+///
+/// `use:` directives can be made optional with `=?`, which skips the
+/// directive if the value is `None`:
+/// ```ignore
+/// div use:tooltip=?{maybe_params};
+/// ```
+/// `style:` directives also accept `=?`, which removes the style property
+/// while the value is `None`.
+///
+/// `clone:` also accepts a field path, which is cloned into a variable with
+/// the name of the last field:
+/// ```ignore
+/// Show clone:self.data { ... }
+/// ```
+///
+/// The name of an `attr:` directive can also be computed at runtime, by
+/// putting an expression in braces followed by a value:
+/// ```ignore
+/// div attr:{name}="value";
+/// ```
 #[derive(Clone)]
 pub struct Directive {
     pub(crate) dir: syn::Ident,
     pub(crate) key: KebabIdentOrStr,
     pub(crate) modifier: Option<syn::Ident>, // on:event:undelegated
     pub(crate) value: Option<Value>,
+    pub(crate) optional: Option<Token![?]>, // use:directive=?{value} or style:key=?{value}
+    pub(crate) target_type: Option<Box<syn::Type>>, // on:event:target::<Type>
 }
 
 impl Parse for Directive {
@@ -51,26 +83,170 @@ impl Parse for Directive {
         let key: KebabIdentOrStr;
         let value: Option<Value>;
         let modifier: Option<syn::Ident>;
+        let mut optional = None;
+        let mut target_type = None;
 
-        if input.peek(syn::token::Brace) {
+        if input.peek(syn::token::Brace) && input.peek2(Token![=]) {
+            // attr:{name}={value}
+            let (braces, tokens) = parse::braced_tokens(input)?;
+            key = KebabIdentOrStr::Dynamic(Value::Block { tokens, braces });
+            modifier = None;
+            let eq = <Token![=]>::parse(input)?;
+            value = Some(Value::parse_or_emit_err(input, eq.span));
+        } else if input.peek(syn::token::Brace) {
             // on:{click}:undelegated
-            let ident = BracedKebabIdent::parse(input)?;
+            let ident = BracedKebabIdent::parse(input).map_err(|e| {
+                syn::Error::new(
+                    e.span(),
+                    format!(
+                        "the `{name}:{{...}}` shorthand only accepts an identifier, \
+                         use `{name}:key={{...}}` for other expressions"
+                    ),
+                )
+            })?;
             key = KebabIdentOrStr::KebabIdent(ident.ident().clone());
             value = Some(ident.into_block_value());
             modifier = try_parse_modifier(input)?;
+            target_type = parse_target_type(input, &name, modifier.as_ref())?;
+        } else if name == "clone" && input.peek(syn::Ident::peek_any) && input.peek2(Token![.]) {
+            // clone:self.data
+            key = KebabIdentOrStr::Field(parse_field_path(input)?);
+            modifier = try_parse_modifier(input)?;
+            value = rollback_err(input, <Token![=]>::parse)
+                .map(|eq| Value::parse_or_emit_err(input, eq.span));
         } else {
             // on:click:undelegated={on_click}
             key = KebabIdentOrStr::parse(input)?;
             modifier = try_parse_modifier(input)?;
-            value = rollback_err(input, <Token![=]>::parse)
-                .map(|eq| Value::parse_or_emit_err(input, eq.span));
-        };
+            target_type = parse_target_type(input, &name, modifier.as_ref())?;
+            value = rollback_err(input, <Token![=]>::parse).map(|eq| {
+                if name == "on" && input.peek(syn::token::Paren) {
+                    return event_tuple_value(input);
+                }
+                optional = rollback_err(input, <Token![?]>::parse);
+                let span = optional.map_or(eq.span, |question| question.span);
+                let value = Value::parse_or_emit_err(input, span);
+                // leptos already removes styles that are `None`, and resets
+                // properties that are `None`
+                if optional.is_some() && (name == "style" || name == "prop") {
+                    optional_value(value)
+                } else {
+                    value
+                }
+            });
+        }
+
+        if let Some(question) = optional {
+            if name != "use" && name != "style" && name != "prop" {
+                emit_error!(
+                    question.span,
+                    "optional values are only supported on `use:`, `style:` and `prop:` directives"
+                );
+            }
+        }
 
         Ok(Self {
             dir: name,
             key,
             modifier,
             value,
+            optional,
+            target_type,
         })
     }
 }
+
+/// Parses the element type of an `on:event:target::<Type>` modifier.
+///
+/// The type is only parsed after a `target` modifier on an `on:` directive,
+/// and is required there.
+fn parse_target_type(
+    input: ParseStream,
+    name: &syn::Ident,
+    modifier: Option<&syn::Ident>,
+) -> syn::Result<Option<Box<syn::Type>>> {
+    let Some(modifier) = modifier.filter(|m| name == "on" && *m == "target") else {
+        return Ok(None);
+    };
+    if !input.peek(Token![::]) {
+        return Err(syn::Error::new(
+            modifier.span(),
+            "expected the element type: `:target::<Type>`",
+        ));
+    }
+    <Token![::]>::parse(input)?;
+    <Token![<]>::parse(input)?;
+    let ty = syn::Type::parse(input)?;
+    <Token![>]>::parse(input)?;
+    Ok(Some(Box::new(ty)))
+}
+
+/// Composes an `on:event=(extractor, setter)` tuple into an event handler,
+/// which sets the `setter` to the value returned by `extractor(&ev)`.
+///
+/// ```text
+/// on:input=(event_target_value, set_value)
+/// => move |ev| set_value.set(event_target_value(&ev).into())
+/// ```
+fn event_tuple_value(input: ParseStream) -> Value {
+    let (parens, inner) = parse::extract_parenthesized(input).expect("peeked parens");
+    let span = parens.span.join();
+
+    // split on top-level commas, allowing a trailing comma
+    let mut parts = vec![TokenStream::new()];
+    while let Ok(tt) = TokenTree::parse(&inner) {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == ',' => parts.push(TokenStream::new()),
+            tt => parts.last_mut().expect("parts is never empty").extend([tt]),
+        }
+    }
+    if parts.last().is_some_and(TokenStream::is_empty) {
+        parts.pop();
+    }
+
+    let tokens = if let [extractor, setter] = &parts[..] {
+        quote_spanned! { span=>
+            let __mview_extractor = #extractor;
+            let __mview_setter = #setter;
+            move |ev| ::leptos::prelude::Set::set(
+                &__mview_setter,
+                ::std::convert::Into::into(__mview_extractor(&ev)),
+            )
+        }
+    } else {
+        emit_error!(
+            span, "expected a tuple of `(extractor, setter)`";
+            help = "the setter is set to the value returned by the extractor, like `on:input=(event_target_value, set_value)`"
+        );
+        quote_spanned! { span=> |_| () }
+    };
+    Value::Block {
+        tokens,
+        braces: syn::token::Brace(span),
+    }
+}
+
+/// Parses a field access path like `self.data` or `state.user.name`.
+///
+/// This is parsed manually instead of as a [`syn::Expr`], which would also
+/// take a method call if the children are in parens (`clone:self.data(...)`).
+fn parse_field_path(input: ParseStream) -> syn::Result<syn::ExprField> {
+    let base = syn::Ident::parse_any(input)?;
+    let mut base = syn::Expr::Path(syn::ExprPath {
+        attrs: Vec::new(),
+        qself: None,
+        path: base.into(),
+    });
+    loop {
+        let field = syn::ExprField {
+            attrs: Vec::new(),
+            base: Box::new(base),
+            dot_token: input.parse()?,
+            member: syn::Member::Named(syn::Ident::parse_any(input)?),
+        };
+        if !input.peek(Token![.]) {
+            return Ok(field);
+        }
+        base = syn::Expr::Field(field);
+    }
+}
@@ -1,16 +1,28 @@
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use proc_macro_error2::emit_error;
+use quote::quote_spanned;
 use syn::{parse::Parse, Token};
 
 use crate::{
     ast::{BracedKebabIdent, KebabIdent, Value},
-    parse::rollback_err,
+    kw,
+    parse::{self, rollback_err},
     span,
 };
 
 /// A `key = value` type of attribute.
 ///
 /// This can either be a normal `key = value`, a shorthand `{key}`, or a
-/// boolean attribute `checked`.
+/// boolean attribute `checked`. Whether the attribute was written with the
+/// shorthand is kept, as `{value}` is expanded differently on form controls.
+///
+/// An optional value `key=?{value}` must be an `Option`, and the attribute is
+/// skipped if it is `None`.
+///
+/// An aliased shorthand `{value as key}` is the same as `key={value}`.
+///
+/// A lazy value `key=lazy{value}` is only computed on the client, after the
+/// view has been hydrated.
 ///
 /// # Examples
 /// ```ignore
@@ -26,37 +38,242 @@ use crate::{
 pub struct KvAttr {
     key: KebabIdent,
     value: Value,
+    shorthand: bool,
 }
 
 impl KvAttr {
-    pub const fn new(key: KebabIdent, value: Value) -> Self { Self { key, value } }
+    pub const fn new(key: KebabIdent, value: Value) -> Self {
+        Self {
+            key,
+            value,
+            shorthand: false,
+        }
+    }
 
     pub const fn key(&self) -> &KebabIdent { &self.key }
 
     pub const fn value(&self) -> &Value { &self.value }
 
     pub fn span(&self) -> Span { span::join(self.key().span(), self.value().span()) }
+
+    /// Whether this attribute was written as a shorthand `{key}`.
+    pub const fn is_shorthand(&self) -> bool { self.shorthand }
+
+    /// Converts a `true` or `false` value to a string literal if this is an
+    /// enumerated HTML attribute with `"true"` and `"false"` keywords (like
+    /// `contenteditable`).
+    ///
+    /// These are not boolean attributes: `contenteditable=false` needs to be
+    /// rendered as `contenteditable="false"` instead of removing it. Only
+    /// literals are converted, as the type of any other value is not known.
+    pub fn keyword_bool_value(&self) -> Option<syn::LitStr> {
+        let Value::Lit(syn::Lit::Bool(b)) = self.value() else {
+            return None;
+        };
+        KEYWORD_BOOL_ATTRIBUTES
+            .contains(&self.key().repr())
+            .then(|| syn::LitStr::new(&b.value.to_string(), b.span))
+    }
 }
 
+/// HTML attributes that take `"true"` and `"false"` as keywords, instead of
+/// being present or absent.
+const KEYWORD_BOOL_ATTRIBUTES: [&str; 3] = ["contenteditable", "draggable", "spellcheck"];
+
 impl Parse for KvAttr {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let (ident, value) = if input.peek(syn::token::Brace) {
+        if input.peek(syn::token::Brace) {
+            if let Some(alias) = rollback_err(input, parse_alias) {
+                return Ok(alias);
+            }
             let braced_ident = BracedKebabIdent::parse(input)?;
-            (
-                braced_ident.ident().clone(),
-                braced_ident.into_block_value(),
-            )
-        } else {
-            let ident = KebabIdent::parse(input)?;
-            if let Some(eq) = rollback_err(input, <Token![=]>::parse) {
-                let value = Value::parse_or_emit_err(input, eq.span);
-                (ident, value)
+            return Ok(Self {
+                key: braced_ident.ident().clone(),
+                value: braced_ident.into_block_value(),
+                shorthand: true,
+            });
+        }
+
+        let ident = KebabIdent::parse(input)?;
+        let value = if let Some(eq) = rollback_err(input, <Token![=]>::parse) {
+            if let Some(question) = rollback_err(input, <Token![?]>::parse) {
+                optional_value(Value::parse_or_emit_err(input, question.span))
+            } else if input.peek(kw::lazy) && input.peek2(syn::token::Brace) {
+                let lazy = kw::lazy::parse(input)?;
+                let (braces, tokens) = parse::braced_tokens(input)?;
+                lazy_value(lazy.span, braces, &tokens)
             } else {
-                let value = Value::new_true();
-                (ident, value)
+                Value::parse_or_emit_err(input, eq.span)
             }
+        } else {
+            // spanned to the key so that type errors (like a non-`bool` component
+            // prop without a value) point to the attribute
+            Value::Lit(syn::LitBool::new(true, ident.span()).into())
         };
 
         Ok(Self::new(ident, value))
     }
 }
+
+/// Parses an aliased shorthand `{value as key}`.
+///
+/// The key is everything after the last `as`, so casts can still be used in the
+/// value (`{count as f64 as value}`).
+fn parse_alias(input: syn::parse::ParseStream) -> syn::Result<KvAttr> {
+    let (braces, tokens) = parse::braced_tokens(input)?;
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let Some(as_index) = tokens
+        .iter()
+        .rposition(|tt| matches!(tt, TokenTree::Ident(ident) if ident == "as"))
+    else {
+        return Err(syn::Error::new(braces.span.join(), "no alias found"));
+    };
+
+    let (value, key) = tokens.split_at(as_index);
+    if value.is_empty() {
+        return Err(syn::Error::new(
+            braces.span.join(),
+            "expected value before `as`",
+        ));
+    }
+    let key = syn::parse2::<KebabIdent>(key[1..].iter().cloned().collect())?;
+
+    Ok(KvAttr {
+        key,
+        value: Value::Block {
+            tokens: value.iter().cloned().collect(),
+            braces,
+        },
+        shorthand: true,
+    })
+}
+
+/// HTML attributes that only accept a fixed set of (case-insensitive) values.
+///
+/// An empty string is included if it is the same as one of the keywords.
+#[cfg(feature = "strict")]
+#[rustfmt::skip]
+const ENUMERATED_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("autocapitalize", &["off", "none", "on", "sentences", "words", "characters"]),
+    ("contenteditable", &["true", "false", "plaintext-only", ""]),
+    ("crossorigin", &["anonymous", "use-credentials", ""]),
+    ("decoding", &["sync", "async", "auto"]),
+    ("dir", &["ltr", "rtl", "auto"]),
+    ("draggable", &["true", "false"]),
+    ("enterkeyhint", &["enter", "done", "go", "next", "previous", "search", "send"]),
+    ("fetchpriority", &["high", "low", "auto"]),
+    ("inputmode", &["none", "text", "decimal", "numeric", "tel", "search", "email", "url"]),
+    ("loading", &["eager", "lazy"]),
+    ("popover", &["auto", "manual", "hint", ""]),
+    (
+        "referrerpolicy",
+        &[
+            "no-referrer",
+            "no-referrer-when-downgrade",
+            "origin",
+            "origin-when-cross-origin",
+            "same-origin",
+            "strict-origin",
+            "strict-origin-when-cross-origin",
+            "unsafe-url",
+            "",
+        ],
+    ),
+    ("spellcheck", &["true", "false", ""]),
+    ("translate", &["yes", "no", ""]),
+];
+
+#[cfg(feature = "strict")]
+impl KvAttr {
+    /// Emits an error if this is an enumerated HTML attribute (like `loading`)
+    /// with a string literal value that is not one of its allowed values.
+    ///
+    /// Only string literals are checked, any other values are left to the
+    /// browser.
+    pub fn validate_enumerated_value(&self) {
+        let Value::Lit(syn::Lit::Str(value)) = self.value() else {
+            return;
+        };
+        let Some((_, allowed)) = ENUMERATED_ATTRIBUTES
+            .iter()
+            .find(|(name, _)| *name == self.key().repr())
+        else {
+            return;
+        };
+
+        if !allowed.contains(&value.value().to_ascii_lowercase().as_str()) {
+            let allowed = allowed
+                .iter()
+                .map(|v| format!("\"{v}\""))
+                .collect::<Vec<_>>()
+                .join(", ");
+            emit_error!(
+                value.span(), "invalid value for `{}`", self.key().repr();
+                help = "expected one of {}", allowed
+            );
+        }
+    }
+}
+
+/// Converts the value of an optional attribute `key=?{value}` to a value that
+/// must be an `Option`.
+///
+/// Leptos already skips attributes (and classes and styles) that are `None`,
+/// so this only adds a type annotation for a better error message if the
+/// value is not an `Option`.
+pub(super) fn optional_value(value: Value) -> Value {
+    match value {
+        Value::Block { tokens, braces } => {
+            let span = braces.span.join();
+            Value::Block {
+                tokens: quote_spanned! { span=>
+                    let value: ::std::option::Option<_> = {#tokens};
+                    value
+                },
+                braces,
+            }
+        }
+        Value::Bracket {
+            tokens,
+            brackets,
+            prefixes: None,
+        } => {
+            let span = brackets.span.join();
+            Value::Bracket {
+                tokens: quote_spanned! { span=>
+                    let value: ::std::option::Option<_> = {#tokens};
+                    value
+                },
+                brackets,
+                prefixes: None,
+            }
+        }
+        other => {
+            emit_error!(
+                other.span(),
+                "optional values must be a block or bracket";
+                help = "remove the `?`"
+            );
+            other
+        }
+    }
+}
+
+/// Converts the value of a lazy attribute `key=lazy{value}` to a value that is
+/// only computed in an effect.
+///
+/// Effects never run on the server, so the attribute is `None` (skipped) when
+/// rendering on the server and while hydrating. The effect then sets the
+/// value, rerunning if any signals in the value change.
+fn lazy_value(span: Span, braces: syn::token::Brace, tokens: &TokenStream) -> Value {
+    Value::Block {
+        tokens: quote_spanned! { span=>
+            let __mview_lazy = ::leptos::prelude::RwSignal::new(::std::option::Option::None);
+            ::leptos::prelude::Effect::new(move || {
+                ::leptos::prelude::Set::set(&__mview_lazy, ::std::option::Option::Some({#tokens}));
+            });
+            move || ::leptos::prelude::Get::get(&__mview_lazy)
+        },
+        braces,
+    }
+}
@@ -1,9 +1,13 @@
+use proc_macro_error2::emit_error;
 use syn::{
     parse::{Parse, ParseStream},
     Token,
 };
 
-use crate::{ast::KebabIdent, parse::rollback_err};
+use crate::{
+    ast::{AutoId, KebabIdent},
+    parse::rollback_err,
+};
 
 /// A shorthand for adding class or ids to an element.
 ///
@@ -20,6 +24,8 @@ use crate::{ast::KebabIdent, parse::rollback_err};
 /// ```ignore
 /// div #important .more-classes #another-id .claaass
 /// ```
+///
+/// A generated id, `#auto(name)`, is parsed separately into an [`AutoId`].
 #[derive(Clone)]
 pub enum SelectorShorthand {
     Id {
@@ -75,7 +81,12 @@ impl Parse for SelectorShorthand {
 }
 
 #[derive(Clone, Default)]
-pub struct SelectorShorthands(Vec<SelectorShorthand>);
+pub struct SelectorShorthands(Vec<SelectorShorthand>, Option<AutoId>);
+
+impl SelectorShorthands {
+    /// The generated id from a `#auto(name)` selector, if there is one.
+    pub const fn auto_id(&self) -> Option<&AutoId> { self.1.as_ref() }
+}
 
 impl std::ops::Deref for SelectorShorthands {
     type Target = [SelectorShorthand];
@@ -84,11 +95,35 @@ impl std::ops::Deref for SelectorShorthands {
 impl Parse for SelectorShorthands {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut vec = Vec::new();
-        while let Some(inner) = rollback_err(input, SelectorShorthand::parse) {
-            vec.push(inner);
+        let mut auto_id = None::<AutoId>;
+        loop {
+            if AutoId::peek(input) {
+                let id = AutoId::parse(input)?;
+                if auto_id.is_some() {
+                    emit_error!(id.name.span(), "an element can only have one `#auto` id");
+                } else {
+                    auto_id = Some(id);
+                }
+            } else if let Some(inner) = rollback_err(input, SelectorShorthand::parse) {
+                vec.push(inner);
+            } else {
+                break;
+            }
+        }
+
+        if let Some(auto_id) = &auto_id {
+            if let Some(SelectorShorthand::Id { id, .. }) = vec
+                .iter()
+                .find(|sel| matches!(sel, SelectorShorthand::Id { .. }))
+            {
+                emit_error!(
+                    id.span(), "`#auto` cannot be combined with other ids";
+                    help = "remove `#{}` or `#auto({})`", id.repr(), auto_id.name
+                );
+            }
         }
 
-        Ok(Self(vec))
+        Ok(Self(vec, auto_id))
     }
 }
 
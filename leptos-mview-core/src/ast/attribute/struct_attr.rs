@@ -0,0 +1,60 @@
+use proc_macro2::Span;
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use super::kv::KvAttr;
+use crate::{ast::KebabIdent, parse::extract_braced, span};
+
+/// A component prop that is built from named fields, like `config:{ a=1 b=2 }`.
+///
+/// The struct is created with `Default::default()`, then each field is set
+/// to its value. The fields are parsed the same as [`KvAttr`]s, so shorthands
+/// (`{a}`) and boolean fields (`enabled`) also work.
+///
+/// The first field must be a `key = value` to tell it apart from a directive
+/// shorthand like `class:{primary}`.
+///
+/// # Examples
+/// ```ignore
+/// Chart config:{ width=300 height={height} animated };
+///       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+/// ```
+#[derive(Clone)]
+pub struct StructAttr {
+    key: KebabIdent,
+    braces: syn::token::Brace,
+    fields: Vec<KvAttr>,
+}
+
+impl StructAttr {
+    pub const fn key(&self) -> &KebabIdent { &self.key }
+
+    pub fn fields(&self) -> &[KvAttr] { &self.fields }
+
+    pub fn span(&self) -> Span { span::join(self.key.span(), self.braces.span.join()) }
+}
+
+impl Parse for StructAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = KebabIdent::parse(input)?;
+        <Token![:]>::parse(input)?;
+        let (braces, inner) = extract_braced(input)?;
+
+        if !(inner.peek(syn::Ident) && inner.peek2(Token![=]) && !inner.peek2(Token![==])) {
+            return Err(inner.error("expected `field = value`"));
+        }
+
+        let mut fields = Vec::new();
+        while !inner.is_empty() {
+            fields.push(inner.parse::<KvAttr>()?);
+        }
+
+        Ok(Self {
+            key,
+            braces,
+            fields,
+        })
+    }
+}
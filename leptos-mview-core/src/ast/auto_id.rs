@@ -0,0 +1,238 @@
+use std::cell::RefCell;
+
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error2::emit_error;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use super::Value;
+use crate::{kw, parse};
+
+/// A generated id selector, which gives an element a unique id that can be
+/// referenced elsewhere in the same macro with `@ref(name)`.
+///
+/// ```text
+/// section aria-labelledby=@ref(title) {
+///     h2 #auto(title) ("Settings")
+/// }
+/// ```
+/// Expands to the same as:
+/// ```text
+/// let __mview_id_title = leptos_mview::__next_auto_id();
+/// section aria-labelledby={format!("mview-title-{}", __mview_id_title)} {
+///     h2 id={format!("mview-title-{}", __mview_id_title)} ("Settings")
+/// }
+/// ```
+///
+/// A new number is taken every time the view is built, so every instance of
+/// the view has different ids. An id defined in the body of a `for` loop takes
+/// a new number for each item, and can only be referenced inside the loop.
+#[derive(Clone)]
+pub struct AutoId {
+    pub(crate) pound_symbol: Token![#],
+    pub(crate) name: syn::Ident,
+}
+
+impl Parse for AutoId {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pound_symbol = <Token![#]>::parse(input)?;
+        kw::auto::parse(input)?;
+        let (_, name) = parse::parenthesized::<syn::Ident>(input)?;
+        AUTO_IDS.with_borrow_mut(|ids| {
+            if ids.defined.iter().any(|(defined, _)| *defined == name) {
+                emit_error!(name.span(), "the id `{}` is already defined", name);
+            } else {
+                let scope = *ids.scopes.last().expect("auto ids should be reset");
+                ids.defined.push((name.clone(), scope));
+            }
+        });
+        Ok(Self { pound_symbol, name })
+    }
+}
+
+impl AutoId {
+    /// Whether the input starts with `#auto(name)`.
+    ///
+    /// A single identifier in parens is never valid children, so this does not
+    /// conflict with an element that has the id `auto` and children.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.parse::<Token![#]>().is_ok()
+            && fork.parse::<kw::auto>().is_ok()
+            && parse::parenthesized::<syn::Ident>(&fork).is_ok()
+    }
+
+    /// The value of the `id` attribute.
+    pub fn value(&self) -> Value {
+        Value::Block {
+            tokens: id_tokens(&self.name),
+            braces: syn::token::Brace(self.pound_symbol.span),
+        }
+    }
+}
+
+/// Parses a reference to a generated id, `@ref(name)`, into its value.
+pub fn parse_id_ref(input: ParseStream) -> syn::Result<Value> {
+    let at = <Token![@]>::parse(input)?;
+    <Token![ref]>::parse(input)?;
+    let (_, name) = parse::parenthesized::<syn::Ident>(input)?;
+    let tokens = id_tokens(&name);
+    AUTO_IDS.with_borrow_mut(|ids| ids.referenced.push((name, ids.scopes.clone())));
+    Ok(Value::Block {
+        tokens,
+        braces: syn::token::Brace(at.span),
+    })
+}
+
+fn id_tokens(name: &syn::Ident) -> TokenStream {
+    let format = format!("mview-{name}-{{}}");
+    let var = id_var(name);
+    quote! { ::std::format!(#format, #var) }
+}
+
+/// The variable holding the number of the id `name`.
+///
+/// Spanned to the macro call so that every id and reference use the same
+/// variable. A number is `Copy`, so it can be used in `move` closures.
+fn id_var(name: &syn::Ident) -> syn::Ident {
+    format_ident!("__mview_id_{}", name, span = Span::call_site())
+}
+
+/// `let` statements with a new number for each of the ids, or `None` if there
+/// are no ids.
+fn new_ids_tokens(names: &[syn::Ident]) -> Option<TokenStream> {
+    if names.is_empty() {
+        return None;
+    }
+    let vars = names.iter().map(id_var);
+    let span = Span::call_site();
+    Some(quote_spanned! { span=>
+        #(let #vars: usize = ::leptos_mview::__next_auto_id();)*
+    })
+}
+
+/// `let` statements with a new number for each `#auto` id that is defined
+/// outside of any `for` loop.
+///
+/// References that do not match any id also get a number, to avoid more errors
+/// after the one from [`emit_undefined_id_refs`].
+pub fn new_auto_id_tokens() -> Option<TokenStream> {
+    AUTO_IDS.with_borrow(|ids| {
+        let mut names = ids.scope_names(ROOT_SCOPE);
+        for (name, scopes) in &ids.referenced {
+            if ids
+                .definition_scope(name)
+                .is_none_or(|scope| !scopes.contains(&scope))
+                && !names.contains(name)
+            {
+                names.push(name.clone());
+            }
+        }
+        new_ids_tokens(&names)
+    })
+}
+
+/// Starts a new scope for `#auto` ids, like the body of a `for` loop.
+///
+/// Ids defined in the scope can only be referenced inside it, and must be
+/// added to the view with [`with_auto_ids`].
+pub fn enter_auto_id_scope() {
+    AUTO_IDS.with_borrow_mut(|ids| {
+        ids.scopes.push(ids.next_scope);
+        ids.next_scope += 1;
+    });
+}
+
+/// Ends the scope started by [`enter_auto_id_scope`], returning the ids that
+/// were defined in it.
+pub fn exit_auto_id_scope() -> Vec<syn::Ident> {
+    AUTO_IDS.with_borrow_mut(|ids| {
+        let scope = ids.scopes.pop().expect("scope should have been entered");
+        ids.scope_names(scope)
+    })
+}
+
+/// Adds a new number for each of the ids before the view.
+pub fn with_auto_ids(names: &[syn::Ident], view: TokenStream) -> TokenStream {
+    match new_ids_tokens(names) {
+        Some(ids) => quote! { { #ids #view } },
+        None => view,
+    }
+}
+
+/// The scope outside of any `for` loop.
+const ROOT_SCOPE: usize = 0;
+
+struct AutoIds {
+    /// Every id with the scope it is defined in.
+    defined: Vec<(syn::Ident, usize)>,
+    /// Every reference with the scopes it is inside of.
+    referenced: Vec<(syn::Ident, Vec<usize>)>,
+    /// The scopes that are currently being parsed, innermost last.
+    scopes: Vec<usize>,
+    next_scope: usize,
+}
+
+impl Default for AutoIds {
+    fn default() -> Self {
+        Self {
+            defined: Vec::new(),
+            referenced: Vec::new(),
+            scopes: vec![ROOT_SCOPE],
+            next_scope: ROOT_SCOPE + 1,
+        }
+    }
+}
+
+impl AutoIds {
+    fn definition_scope(&self, name: &syn::Ident) -> Option<usize> {
+        self.defined
+            .iter()
+            .find(|(defined, _)| defined == name)
+            .map(|(_, scope)| *scope)
+    }
+
+    fn scope_names(&self, scope: usize) -> Vec<syn::Ident> {
+        self.defined
+            .iter()
+            .filter(|(_, defined_in)| *defined_in == scope)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+thread_local! {
+    /// The ids defined and referenced in the macro that is being parsed.
+    static AUTO_IDS: RefCell<AutoIds> = RefCell::default();
+}
+
+/// Clears the ids from any previous macro call. Must be called before parsing
+/// the macro input.
+pub fn reset_auto_ids() { AUTO_IDS.take(); }
+
+/// Emits an error for every `@ref(name)` without a matching `#auto(name)` in
+/// the same or an enclosing scope.
+///
+/// The ids are kept until the next macro call, as they are still needed by
+/// [`new_auto_id_tokens`] while expanding.
+pub fn emit_undefined_id_refs() {
+    AUTO_IDS.with_borrow(|ids| {
+        for (name, scopes) in &ids.referenced {
+            match ids.definition_scope(name) {
+                None => emit_error!(
+                    name.span(), "no element has the id `{}`", name;
+                    help = "add `#auto({})` to the element to give it an id", name
+                ),
+                Some(scope) if !scopes.contains(&scope) => emit_error!(
+                    name.span(), "the id `{}` is defined inside a `for` loop", name;
+                    help = "each item of the loop has its own id, so it can only be \
+                        referenced inside the loop"
+                ),
+                Some(_) => {}
+            }
+        }
+    });
+}
@@ -1,13 +1,16 @@
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenTree};
 use proc_macro_error2::emit_error;
-use quote::{quote, ToTokens};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::{
     ext::IdentExt,
     parse::{Parse, ParseStream},
     parse_quote, Token,
 };
 
-use super::{Doctype, Element};
+use super::{
+    Doctype, Element, ErrBoundary, ForLoop, LiveRegion, MatchView, ResourceView, ShowHidden,
+    WithBlock,
+};
 use crate::{
     ast::Value,
     error_ext::SynErrorExt,
@@ -52,11 +55,19 @@ impl NodeChild {
 /// If the child is a `Value::Lit`, this lit must be a string. Parsing will
 /// abort if the lit is not a string.
 ///
-/// Children can either be a [`NodeChild`] (i.e. an actual element), or a slot.
-/// Slots are distinguished by prefixing the child with `slot:`.
+/// Children can either be a [`NodeChild`] (i.e. an actual element), a slot, or
+/// a view prop. Slots are distinguished by prefixing the child with `slot:`,
+/// and view props are a name followed by `:` and children, like
+/// `fallback: ( ... )`.
+///
+/// A bare `children` is a shorthand for `{children()}`, `raw_text(value)`
+/// renders already escaped HTML inside a `span`, and `each(value)` renders an
+/// iterator of views (`each?(value)` for an `Option` of an iterator). A bare
+/// `space` or `nbsp` is a text node with a single space or non-breaking space.
 pub enum Child {
     Node(NodeChild),
     Slot(kw::slot, Element),
+    Prop(syn::Ident, Children),
 }
 
 impl Parse for Child {
@@ -79,6 +90,78 @@ impl Parse for Child {
             <Token![:]>::parse(input).unwrap();
             let elem = Element::parse(input)?;
             Ok(Self::Slot(slot, elem))
+        // `name: (...)` passes a view to the `name` prop of a component
+        } else if input.peek(syn::Ident)
+            && input.peek2(Token![:])
+            && !input.peek2(Token![::])
+            && (input.peek3(syn::token::Paren) || input.peek3(syn::token::Brace))
+        {
+            let name = syn::Ident::parse(input).unwrap();
+            <Token![:]>::parse(input).unwrap();
+            let children = if input.peek(syn::token::Brace) {
+                parse::braced::<Children>(input)?.1
+            } else {
+                parse::parenthesized::<Children>(input)?.1
+            };
+            Ok(Self::Prop(name, children))
+        // a bare `children` calls the `children` prop: no element has this name
+        } else if input.peek(kw::children) && !input.peek2(Token![::]) && !input.peek2(Token![-]) {
+            let children = kw::children::parse(input).unwrap();
+            Ok(Self::Node(NodeChild::Value(Value::Block {
+                tokens: quote_spanned! { children.span=> children() },
+                braces: syn::token::Brace(children.span),
+            })))
+        // explicit whitespace: no element has these names either
+        } else if input.peek(kw::space) && !input.peek2(Token![::]) && !input.peek2(Token![-]) {
+            let space = kw::space::parse(input).unwrap();
+            let text = syn::LitStr::new(" ", space.span);
+            Ok(Self::Node(NodeChild::Value(Value::Lit(text.into()))))
+        } else if input.peek(kw::nbsp) && !input.peek2(Token![::]) && !input.peek2(Token![-]) {
+            let nbsp = kw::nbsp::parse(input).unwrap();
+            let text = syn::LitStr::new("\u{a0}", nbsp.span);
+            Ok(Self::Node(NodeChild::Value(Value::Lit(text.into()))))
+        // no pre-escaped text node exists, so `raw_text` uses a span
+        } else if input.peek(kw::raw_text) && input.peek2(syn::token::Paren) {
+            let raw_text = kw::raw_text::parse(input).unwrap();
+            let (_, value) = parse::parenthesized::<Value>(input)?;
+            let span = raw_text.span;
+            Ok(Self::Node(NodeChild::Value(Value::Block {
+                tokens: quote_spanned! { span=>
+                    ::leptos::tachys::view::add_attr::AddAnyAttr::add_any_attr(
+                        ::leptos::tachys::html::element::span(),
+                        ::leptos::tachys::html::element::inner_html(#value),
+                    )
+                },
+                braces: syn::token::Brace(span),
+            })))
+        // `each` collects an iterator of views, rerunning if it is a bracket.
+        // `each?` takes an `Option`, rendering nothing if it is `None`.
+        } else if input.peek(kw::each) && (input.peek2(syn::token::Paren) || input.peek2(Token![?]))
+        {
+            Ok(Self::Node(NodeChild::Value(parse_each(input)?)))
+        // `@name` uses a template defined at the start of the macro
+        } else if input.peek(Token![@]) && input.peek2(syn::Ident) {
+            let at = <Token![@]>::parse(input).unwrap();
+            let name = syn::Ident::parse(input).unwrap();
+            Ok(Self::Node(NodeChild::Value(Value::Block {
+                tokens: quote_spanned! { name.span()=> #name() },
+                braces: syn::token::Brace(at.span),
+            })))
+        } else if input.peek(Token![for]) {
+            let value = ForLoop::parse(input)?.into_value();
+            Ok(Self::Node(NodeChild::Value(value)))
+        } else if input.peek(Token![match]) {
+            let value = MatchView::parse(input)?.into_value();
+            Ok(Self::Node(NodeChild::Value(value)))
+        } else if input.peek(kw::err_boundary) && !input.peek2(Token![::]) {
+            let elem = ErrBoundary::parse(input)?.into_element();
+            Ok(Self::Node(NodeChild::Element(elem)))
+        } else if input.peek(kw::resource) && input.peek2(syn::token::Brace) {
+            let elem = ResourceView::parse(input)?.into_element();
+            Ok(Self::Node(NodeChild::Element(elem)))
+        } else if input.peek(kw::live_region) && input.peek2(syn::token::Paren) {
+            let elem = LiveRegion::parse(input)?.into_element();
+            Ok(Self::Node(NodeChild::Element(elem)))
         } else if input.peek(syn::Ident::peek_any) {
             let elem = Element::parse(input)?;
             Ok(Self::Node(NodeChild::Element(elem)))
@@ -90,6 +173,47 @@ impl Parse for Child {
     }
 }
 
+/// Parses `each(value)` or `each?(value)` into a block that collects the
+/// views.
+fn parse_each(input: ParseStream) -> syn::Result<Value> {
+    let each = kw::each::parse(input).unwrap();
+    let optional = rollback_err(input, <Token![?]>::parse).is_some();
+    let (_, value) = parse::parenthesized::<Value>(input)?;
+    let span = each.span;
+    let (is_bracket, items) = if let Value::Bracket {
+        tokens,
+        prefixes: None,
+        ..
+    } = &value
+    {
+        (true, quote! { {#tokens} })
+    } else {
+        (false, value.into_token_stream())
+    };
+    let view = if optional {
+        quote_spanned! { span=>
+            {
+                let items: ::std::option::Option<_> = #items;
+                ::std::option::Option::map(
+                    items,
+                    ::leptos::prelude::CollectView::collect_view,
+                )
+            }
+        }
+    } else {
+        quote_spanned! { span=> ::leptos::prelude::CollectView::collect_view(#items) }
+    };
+    let tokens = if is_bracket {
+        quote_spanned! { span=> move || #view }
+    } else {
+        view
+    };
+    Ok(Value::Block {
+        tokens,
+        braces: syn::token::Brace(span),
+    })
+}
+
 /// A space-separated series of children.
 ///
 /// Parsing does not include the surrounding braces.
@@ -104,13 +228,42 @@ impl std::ops::Deref for Children {
 impl Parse for Children {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut vec = Vec::new();
+        // whether the last child failed to parse, to avoid cascading errors while
+        // skipping over invalid tokens
+        let mut recovering = false;
 
         loop {
             if input.is_empty() {
                 break;
             }
+            #[cfg(feature = "trim-whitespace")]
+            if peek_whitespace_str(input) {
+                input.parse::<syn::LitStr>().unwrap();
+                continue;
+            }
+            // `with` blocks are flattened into the surrounding children
+            if WithBlock::peek(input) {
+                match WithBlock::parse(input) {
+                    Ok(with) => vec.extend(with.into_children().into_vec()),
+                    Err(e) => e.emit_as_error(),
+                }
+                continue;
+            }
+            // so are the children of `show_hidden`
+            if ShowHidden::peek(input) {
+                match ShowHidden::parse(input) {
+                    Ok(show_hidden) => vec.extend(show_hidden.into_children().into_vec()),
+                    Err(e) => e.emit_as_error(),
+                }
+                continue;
+            }
+            let is_element = input.peek(syn::Ident::peek_any);
+            let start = input.fork();
             match Child::parse(input) {
-                Ok(child) => vec.push(child),
+                Ok(child) => {
+                    vec.push(child);
+                    recovering = false;
+                }
                 Err(e) => {
                     if input.peek(Token![;]) {
                         // an extra semi-colon: just skip it and keep parsing
@@ -119,11 +272,23 @@ impl Parse for Children {
                             help="remove this semi-colon"
                         );
                         <Token![;]>::parse(input).unwrap();
+                    } else if let Some(child) = (!recovering)
+                        .then(|| parse_unwrapped_expr(&start, input))
+                        .flatten()
+                    {
+                        vec.push(child);
                     } else {
-                        e.emit_as_error();
-                        // skip the rest of the tokens
-                        // need to consume all tokens otherwise an error is made on drop
-                        parse::take_rest(input);
+                        if !recovering {
+                            e.emit_as_error();
+                        }
+                        recovering = true;
+                        // skip the invalid child and keep parsing, so that errors in
+                        // the rest of the children are also reported.
+                        if is_element {
+                            parse::skip_element(input);
+                        } else if !input.is_empty() {
+                            TokenTree::parse(input).unwrap();
+                        }
                     }
                 }
             };
@@ -133,22 +298,97 @@ impl Parse for Children {
     }
 }
 
+/// Whether the next child is a string literal that only contains whitespace.
+///
+/// The `space` and `nbsp` keywords are not string literals, so they are kept.
+#[cfg(feature = "trim-whitespace")]
+fn peek_whitespace_str(input: ParseStream) -> bool {
+    input
+        .fork()
+        .parse::<syn::LitStr>()
+        .is_ok_and(|s| s.value().chars().all(|c| c.is_ascii_whitespace()))
+}
+
+/// Tries to parse the rest of the input from `start` as a Rust expression that
+/// is missing its surrounding braces, like `Comp { a + 1 }` instead of
+/// `Comp { {a + 1} }`. Only used after a child has failed to parse.
+///
+/// Only expressions that can never be valid children are checked (some
+/// operators, casts and macros), as a single identifier is an attribute
+/// shorthand and things like `a.b()` are valid elements. Operators that are
+/// also used by the macro are skipped, like `-` in `div.kebab-class(...)` and
+/// `|` in `Comp |arg| (...)`.
+///
+/// An error is emitted and the expression is used as a block child to avoid
+/// cascading errors.
+fn parse_unwrapped_expr(start: ParseStream, input: ParseStream) -> Option<Child> {
+    let fork = start.fork();
+    let expr = fork.parse::<syn::Expr>().ok()?;
+    let is_expr = match &expr {
+        syn::Expr::Binary(binary) => matches!(
+            binary.op,
+            syn::BinOp::Add(_)
+                | syn::BinOp::Mul(_)
+                | syn::BinOp::Div(_)
+                | syn::BinOp::Rem(_)
+                | syn::BinOp::And(_)
+                | syn::BinOp::Or(_)
+                | syn::BinOp::Eq(_)
+                | syn::BinOp::Ne(_)
+        ),
+        syn::Expr::Unary(_)
+        | syn::Expr::Reference(_)
+        | syn::Expr::Cast(_)
+        | syn::Expr::Macro(_) => true,
+        _ => false,
+    };
+    if !fork.is_empty() || !is_expr {
+        return None;
+    }
+
+    let span = start.span();
+    let tokens = parse::take_rest(start);
+    // skip what is left after the failed child
+    parse::take_rest(input);
+    emit_error!(
+        tokens, "expected children, found an expression";
+        help = "wrap the expression in braces to use it as a child: `{...}`"
+    );
+    Some(Child::Node(NodeChild::Value(Value::Block {
+        tokens,
+        braces: syn::token::Brace(span),
+    })))
+}
+
 impl Children {
+    pub const fn new(children: Vec<Child>) -> Self { Self(children) }
+
     pub fn into_vec(self) -> Vec<Child> { self.0 }
 
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Child> { self.0.iter_mut() }
+
     /// Returns an iterator of all children that are not slots.
     pub fn node_children(&self) -> impl Iterator<Item = &NodeChild> {
         self.0.iter().filter_map(|child| match child {
             Child::Node(node) => Some(node),
-            Child::Slot(..) => None,
+            Child::Slot(..) | Child::Prop(..) => None,
         })
     }
 
     /// Returns an iterator of all children that are slots.
     pub fn slot_children(&self) -> impl Iterator<Item = &Element> {
         self.0.iter().filter_map(|child| match child {
-            Child::Node(_) => None,
+            Child::Node(_) | Child::Prop(..) => None,
             Child::Slot(_, elem) => Some(elem),
         })
     }
+
+    /// Returns an iterator of all children that are view props, with the name
+    /// of the prop.
+    pub fn prop_children(&self) -> impl Iterator<Item = (&syn::Ident, &Self)> {
+        self.0.iter().filter_map(|child| match child {
+            Child::Prop(name, children) => Some((name, children)),
+            Child::Node(_) | Child::Slot(..) => None,
+        })
+    }
 }
@@ -1,12 +1,15 @@
-use proc_macro2::{TokenStream, TokenTree};
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
 use proc_macro_error2::emit_error;
 use quote::{ToTokens, TokenStreamExt};
 use syn::{
     parse::{Parse, ParseStream},
-    Token,
+    parse_quote_spanned, Token,
 };
 
-use super::{attribute::selector::SelectorShorthands, Attrs, Children, Tag};
+use super::{
+    attribute::{directive::Directive, kv::KvAttr, selector::SelectorShorthands},
+    Attr, Attrs, AutoId, Children, KebabIdentOrStr, Tag,
+};
 use crate::{
     expand::{component_to_tokens, xml_to_tokens},
     parse::{self, rollback_err},
@@ -53,8 +56,30 @@ pub struct Element {
 impl Parse for Element {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let tag = Tag::parse(input)?;
+        Self::parse_with_tag(tag, input)
+    }
+}
+
+impl Element {
+    /// Parses the rest of an element (selectors, attributes and children)
+    /// after the tag has already been parsed.
+    ///
+    /// Used by constructs that desugar to a fixed tag, like `err_boundary`.
+    pub fn parse_with_tag(tag: Tag, input: ParseStream) -> syn::Result<Self> {
         let selectors = SelectorShorthands::parse(input)?;
-        let attrs = Attrs::parse(input)?;
+        let mut attrs = Attrs::parse(input)?;
+        if let Some(auto_id) = selectors.auto_id() {
+            attrs.push(auto_id_attr(&tag, auto_id));
+        }
+
+        #[cfg(feature = "strict")]
+        if tag.kind() == super::TagKind::Html {
+            for attr in attrs.iter() {
+                if let super::Attr::Kv(kv) = attr {
+                    kv.validate_enumerated_value();
+                }
+            }
+        }
 
         if rollback_err(input, <Token![;]>::parse).is_some() {
             // no children, terminated by semicolon.
@@ -69,12 +94,17 @@ impl Parse for Element {
                 help = "add a `;` to terminate the element with no children"
             );
             Ok(Self::new(tag, selectors, attrs, None, None))
+        } else if matches!(tag, Tag::Component(_)) && emit_error_if_positional_args(input) {
+            // skip the args and continue as if there are no children
+            TokenTree::parse(input)?;
+            Ok(Self::new(tag, selectors, attrs, None, None))
         } else if input.peek(syn::token::Brace) || input.peek(syn::token::Paren) {
             let children = if input.peek(syn::token::Brace) {
                 parse::braced::<Children>(input)?.1
             } else {
                 parse::parenthesized::<Children>(input)?.1
             };
+            emit_error_if_view_props(&tag, &children);
 
             Ok(Self::new(tag, selectors, attrs, None, Some(children)))
         } else if input.peek(Token![|]) {
@@ -92,7 +122,14 @@ impl Parse for Element {
                 );
                 None
             };
+            if let Some(children) = &children {
+                emit_error_if_view_props(&tag, children);
+            }
             Ok(Self::new(tag, selectors, attrs, Some(args), children))
+        } else if selectors.is_empty() && attrs.is_empty() && input.cursor().punct().is_some() {
+            // might be an expression missing its braces, like `count + 1`:
+            // leave the error to the children, which check for this
+            Err(input.error("unknown attribute"))
         } else {
             // add error at the unknown token
             // continue trying to parse as if there are no children
@@ -137,9 +174,70 @@ impl Element {
 
     pub const fn attrs(&self) -> &Attrs { &self.attrs }
 
+    pub const fn attrs_mut(&mut self) -> &mut Attrs { &mut self.attrs }
+
     pub const fn children_args(&self) -> Option<&TokenStream> { self.children_args.as_ref() }
 
     pub const fn children(&self) -> Option<&Children> { self.children.as_ref() }
+
+    pub const fn children_mut(&mut self) -> Option<&mut Children> { self.children.as_mut() }
+}
+
+/// Converts a `#auto(name)` selector into an `id` attribute, or `attr:id` on
+/// components so that it is forwarded to the root element(s).
+fn auto_id_attr(tag: &Tag, auto_id: &AutoId) -> Attr {
+    let span = auto_id.pound_symbol.span;
+    if matches!(tag, Tag::Component(_)) {
+        Attr::Directive(Directive {
+            dir: syn::Ident::new("attr", span),
+            key: KebabIdentOrStr::KebabIdent(parse_quote_spanned! { span=> id }),
+            modifier: None,
+            value: Some(auto_id.value()),
+            optional: None,
+            target_type: None,
+        })
+    } else {
+        Attr::Kv(KvAttr::new(
+            parse_quote_spanned! { span=> id },
+            auto_id.value(),
+        ))
+    }
+}
+
+/// View props (`name: (...)`) are only passed to components, so emit an error
+/// if they are used anywhere else.
+fn emit_error_if_view_props(tag: &Tag, children: &Children) {
+    if !matches!(tag, Tag::Component(_)) {
+        for (name, _) in children.prop_children() {
+            emit_error!(name.span(), "view props are only supported on components");
+        }
+    }
+}
+
+/// Checks if the input starts with comma-separated arguments in parentheses,
+/// like `Pair(1, 2)`, and emits an error if so. Returns whether there are any.
+///
+/// Components only take named props, as the macro does not know the order of
+/// a component's props. Children in parentheses are never separated by
+/// commas, so a top-level comma means positional arguments were intended.
+fn emit_error_if_positional_args(input: ParseStream) -> bool {
+    let Some((inner, _, _)) = input.cursor().group(Delimiter::Parenthesis) else {
+        return false;
+    };
+    let mut cursor = inner;
+    while let Some((tt, next)) = cursor.token_tree() {
+        if let TokenTree::Punct(punct) = tt {
+            if punct.as_char() == ',' {
+                emit_error!(
+                    punct.span(), "components do not take positional arguments";
+                    help = "pass each prop by name instead, like `Component a={x} b={y}`"
+                );
+                return true;
+            }
+        }
+        cursor = next;
+    }
+    false
 }
 
 /// Parses closure arguments like `|binding|` or `|(index, item)|`.
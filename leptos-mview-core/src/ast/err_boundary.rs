@@ -0,0 +1,52 @@
+use quote::quote_spanned;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote_spanned,
+};
+
+use super::{attribute::kv::KvAttr, Attr, Element, Tag, Value};
+use crate::kw;
+
+/// The `err_boundary` construct, a shorthand for Leptos' `ErrorBoundary`.
+///
+/// Any children that are a `Result` render their `Ok` value, and the
+/// `fallback` is rendered instead if any of them are `Err`.
+///
+/// ```text
+/// err_boundary fallback=[mview! { "oops" }] ( {fallible()} )
+/// ```
+/// Expands to the same as:
+/// ```text
+/// leptos::error::ErrorBoundary fallback={move |_| mview! { "oops" }} ( {fallible()} )
+/// ```
+///
+/// The `ErrorBoundary` fallback takes the current errors as an argument, so a
+/// bracketed `fallback` ignores this argument. Pass a block instead (like
+/// `fallback={|errors| ...}`) to use the errors.
+pub struct ErrBoundary(Element);
+
+impl Parse for ErrBoundary {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let kw = kw::err_boundary::parse(input)?;
+        let tag = Tag::Component(parse_quote_spanned! { kw.span=> ::leptos::error::ErrorBoundary });
+        let mut element = Element::parse_with_tag(tag, input)?;
+
+        for attr in element.attrs_mut().iter_mut() {
+            let Attr::Kv(kv) = attr else { continue };
+            if kv.key().repr() == "fallback" && matches!(kv.value(), Value::Bracket { .. }) {
+                let value = kv.value();
+                let span = value.span();
+                *kv = KvAttr::new(kv.key().clone(), Value::Block {
+                    tokens: quote_spanned! { span=> move |_| (#value)() },
+                    braces: syn::token::Brace(span),
+                });
+            }
+        }
+
+        Ok(Self(element))
+    }
+}
+
+impl ErrBoundary {
+    pub fn into_element(self) -> Element { self.0 }
+}
@@ -0,0 +1,209 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error2::emit_error;
+use quote::{quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use super::{enter_auto_id_scope, exit_auto_id_scope, with_auto_ids, Children, Value};
+use crate::{expand::root_children_tokens, kw, parse};
+
+/// The `for` construct, a static loop over an iterator.
+///
+/// ```text
+/// for {item} in {list} ( li({item}) ) else ( "No items" )
+/// ```
+/// Expands to the same as:
+/// ```text
+/// {
+///     let mut iter = list.into_iter().peekable();
+///     if iter.peek().is_none() {
+///         "No items".into_any()
+///     } else {
+///         iter.map(|item| mview! { li({item}) }).collect_view().into_any()
+///     }
+/// }
+/// ```
+///
+/// The `else` branch is optional. If the iterator is a bracket (like
+/// `[list.get()]`), the whole loop is wrapped in a `move ||` closure and will
+/// rerender when the list changes.
+///
+/// Adding a `key={...}` after the iterator uses Leptos' keyed `For` instead:
+/// ```text
+/// for {item} in {list} key={|item| item.id} ( li({item.name}) )
+/// ```
+/// Where `list` is a signal (read with `.get()`), or a bracket that is used
+/// as the `each` closure.
+pub struct ForLoop {
+    for_token: Token![for],
+    pattern: TokenStream,
+    iter: Value,
+    key: Option<Value>,
+    children: Children,
+    /// The `#auto` ids defined in the children, which are new for each item.
+    auto_ids: Vec<syn::Ident>,
+    fallback: Option<Children>,
+}
+
+impl Parse for ForLoop {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let for_token = <Token![for]>::parse(input)?;
+        let (_, pattern) = parse::braced_tokens(input)?;
+        <Token![in]>::parse(input)?;
+        let iter = Value::parse(input)?;
+        let key = if input.peek(kw::key) && input.peek2(Token![=]) {
+            kw::key::parse(input)?;
+            let eq = <Token![=]>::parse(input)?;
+            Some(Value::parse_or_emit_err(input, eq.span))
+        } else {
+            None
+        };
+        enter_auto_id_scope();
+        let children = parse_children(input);
+        let auto_ids = exit_auto_id_scope();
+        let children = children?;
+        let fallback = if input.peek(Token![else]) {
+            let else_token = <Token![else]>::parse(input)?;
+            if key.is_some() {
+                emit_error!(else_token.span, "`else` is not supported on keyed loops");
+            }
+            Some(parse_children(input)?)
+        } else {
+            None
+        };
+
+        let fallback_slots = fallback.iter().flat_map(Children::slot_children);
+        for slot in children.slot_children().chain(fallback_slots) {
+            emit_error!(slot.tag().span(), "slots are not supported inside `for`");
+        }
+        let fallback_props = fallback.iter().flat_map(Children::prop_children);
+        for (name, _) in children.prop_children().chain(fallback_props) {
+            emit_error!(name.span(), "view props are not supported inside `for`");
+        }
+
+        Ok(Self {
+            for_token,
+            pattern,
+            iter,
+            key,
+            children,
+            auto_ids,
+            fallback,
+        })
+    }
+}
+
+/// Parses the children of a branch in either braces or parens.
+fn parse_children(input: ParseStream) -> syn::Result<Children> {
+    if input.peek(syn::token::Brace) {
+        Ok(parse::braced::<Children>(input)?.1)
+    } else if input.peek(syn::token::Paren) {
+        Ok(parse::parenthesized::<Children>(input)?.1)
+    } else {
+        Err(input.error("expected children block in braces or parens"))
+    }
+}
+
+impl ForLoop {
+    /// Converts this loop into a block value, to be used as a child.
+    pub fn into_value(self) -> Value {
+        let span = self.for_token.span;
+        Value::Block {
+            tokens: self.into_token_stream(),
+            braces: syn::token::Brace(span),
+        }
+    }
+}
+
+impl ToTokens for ForLoop {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let span = self.for_token.span;
+        let pattern = &self.pattern;
+        let children = root_children_tokens(self.children.node_children(), Span::call_site());
+        // each item gets its own generated ids
+        let children = with_auto_ids(&self.auto_ids, children);
+
+        if let Some(key) = &self.key {
+            tokens.extend(self.keyed_tokens(key, &children));
+            return;
+        }
+
+        // a bracket without a prefix reruns the whole loop
+        let (iter, is_reactive) = match &self.iter {
+            Value::Bracket {
+                tokens,
+                prefixes: None,
+                ..
+            } => (quote_spanned! { span=> {#tokens} }, true),
+            other => (other.to_token_stream(), false),
+        };
+
+        let view = if let Some(fallback) = &self.fallback {
+            let fallback = root_children_tokens(fallback.node_children(), Span::call_site());
+            quote_spanned! { span=>
+                let mut __mview_iter = ::std::iter::Iterator::peekable(
+                    ::std::iter::IntoIterator::into_iter(#iter)
+                );
+                if ::std::iter::Peekable::peek(&mut __mview_iter).is_none() {
+                    ::leptos::prelude::IntoAny::into_any(#fallback)
+                } else {
+                    ::leptos::prelude::IntoAny::into_any(
+                        ::leptos::prelude::CollectView::collect_view(
+                            ::std::iter::Iterator::map(__mview_iter, |#pattern| #children)
+                        )
+                    )
+                }
+            }
+        } else {
+            quote_spanned! { span=>
+                ::leptos::prelude::CollectView::collect_view(
+                    ::std::iter::Iterator::map(
+                        ::std::iter::IntoIterator::into_iter(#iter),
+                        |#pattern| #children
+                    )
+                )
+            }
+        };
+
+        tokens.extend(if is_reactive {
+            quote_spanned! { span=> move || { #view } }
+        } else {
+            quote_spanned! { span=> { #view } }
+        });
+    }
+}
+
+impl ForLoop {
+    /// Expands a loop with a `key` to Leptos' `For` component.
+    fn keyed_tokens(&self, key: &Value, children: &TokenStream) -> TokenStream {
+        let span = self.for_token.span;
+        let pattern = &self.pattern;
+        let each = match &self.iter {
+            Value::Bracket {
+                tokens,
+                prefixes: None,
+                ..
+            } => quote_spanned! { span=> move || {#tokens} },
+            other => quote_spanned! { span=>
+                {
+                    let __mview_each = #other;
+                    move || ::leptos::prelude::Get::get(&__mview_each)
+                }
+            },
+        };
+        let for_component = quote_spanned! { span=> ::leptos::prelude::For };
+
+        quote_spanned! { span=>
+            ::leptos::component::component_view(
+                &#for_component,
+                ::leptos::component::component_props_builder(&#for_component)
+                    .each(#each)
+                    .key(#key)
+                    .children(move |#pattern| #children)
+                    .build()
+            )
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use proc_macro2::TokenStream;
+use proc_macro_error2::emit_error;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use super::{KebabIdent, Value};
+use crate::{kw, parse::rollback_err};
+
+/// A global event listener added at the start of the macro.
+///
+/// ```text
+/// on:keydown={handler};
+/// ```
+/// Expands to:
+/// ```text
+/// let mut handler = Some(handler);
+/// Effect::new(move || {
+///     if let Some(handler) = handler.take() {
+///         let handle = window_event_listener(ev::keydown, handler);
+///         on_cleanup(move || handle.remove());
+///     }
+/// });
+/// ```
+///
+/// The listener is added to the `window` when the view is mounted, and removed
+/// when the current owner is cleaned up. Effects do not run on the server, so
+/// this does nothing in SSR.
+pub struct GlobalListener {
+    on_token: kw::on,
+    event: KebabIdent,
+    value: Value,
+}
+
+impl Parse for GlobalListener {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let on_token = kw::on::parse(input)?;
+        <Token![:]>::parse(input)?;
+        let event = KebabIdent::parse(input)?;
+        if let Some(colon) = rollback_err(input, <Token![:]>::parse) {
+            emit_error!(
+                colon.span,
+                "modifiers are not supported on global event listeners"
+            );
+            syn::Ident::parse_any(input)?;
+        }
+        <Token![=]>::parse(input)?;
+        let value = Value::parse(input)?;
+        <Token![;]>::parse(input)?;
+
+        Ok(Self {
+            on_token,
+            event,
+            value,
+        })
+    }
+}
+
+impl GlobalListener {
+    /// Whether the input starts with an `on:` directive.
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(kw::on) && input.peek2(Token![:]) && !input.peek2(Token![::])
+    }
+}
+
+impl ToTokens for GlobalListener {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let span = self.on_token.span;
+        let event = self.event.to_snake_ident();
+        let value = self.value.to_handler();
+        let event = quote_spanned! { span=> ::leptos::tachys::html::event::#event };
+        let window_event_listener =
+            quote_spanned! { span=> ::leptos::prelude::window_event_listener };
+        // the closure needs to know the event type before it is called
+        tokens.extend(quote! {
+            {
+                fn __mview_global_handler<E, F>(_: &E, f: F) -> F
+                where
+                    E: ::leptos::tachys::html::event::EventDescriptor,
+                    F: ::std::ops::Fn(E::EventType) + 'static,
+                {
+                    f
+                }
+                let event = #event;
+                let mut handler =
+                    ::std::option::Option::Some(__mview_global_handler(&event, #value));
+                ::leptos::prelude::Effect::new(move || {
+                    if let ::std::option::Option::Some(handler) = handler.take() {
+                        let handle = #window_event_listener(event.clone(), handler);
+                        ::leptos::prelude::on_cleanup(move || handle.remove());
+                    }
+                });
+            }
+        });
+    }
+}
@@ -4,6 +4,7 @@ use quote::{quote, ToTokens};
 use syn::{
     ext::IdentExt,
     parse::{Parse, ParseStream},
+    spanned::Spanned,
     token::Brace,
     Token,
 };
@@ -183,10 +184,17 @@ impl PartialEq for KebabIdent {
 impl Eq for KebabIdent {}
 
 // Parse either a kebab-case ident or a str literal.
+//
+// The `Dynamic` and `Field` variants are never created by parsing this
+// directly. `Dynamic` is only used for directive names computed with a block
+// (`attr:{name}={value}` or `class:{name}={value}`), and `Field` for field
+// paths in `clone:self.data`.
 #[derive(Clone)]
 pub enum KebabIdentOrStr {
     KebabIdent(KebabIdent),
     Str(syn::LitStr),
+    Dynamic(Value),
+    Field(syn::ExprField),
 }
 
 impl KebabIdentOrStr {
@@ -194,6 +202,14 @@ impl KebabIdentOrStr {
         match self {
             Self::KebabIdent(ident) => ident.to_lit_str(),
             Self::Str(s) => s.clone(),
+            Self::Dynamic(value) => {
+                emit_dynamic_name_error(value);
+                syn::LitStr::new("", value.span())
+            }
+            Self::Field(field) => {
+                emit_field_path_error(field);
+                syn::LitStr::new("", field.span())
+            }
         }
     }
 
@@ -204,6 +220,14 @@ impl KebabIdentOrStr {
                 emit_error!(s.span(), "expected identifier");
                 syn::Ident::new("__invalid_identifier_found_str", s.span())
             }
+            Self::Dynamic(value) => {
+                emit_dynamic_name_error(value);
+                syn::Ident::new("__invalid_identifier_found_dynamic", value.span())
+            }
+            Self::Field(field) => {
+                emit_field_path_error(field);
+                syn::Ident::new("__invalid_identifier_found_field", field.span())
+            }
         }
     }
 
@@ -211,10 +235,32 @@ impl KebabIdentOrStr {
         match self {
             Self::KebabIdent(kebab_ident) => kebab_ident.repr().to_string(),
             Self::Str(lit_str) => lit_str.value(),
+            Self::Dynamic(value) => {
+                emit_dynamic_name_error(value);
+                String::new()
+            }
+            Self::Field(field) => {
+                emit_field_path_error(field);
+                String::new()
+            }
         }
     }
 }
 
+fn emit_dynamic_name_error(value: &Value) {
+    emit_error!(
+        value.span(),
+        "dynamic names are only supported on `attr:`, `class:` and `style:` directives"
+    );
+}
+
+fn emit_field_path_error(field: &syn::ExprField) {
+    emit_error!(
+        field.span(),
+        "field paths are only supported on `clone:` directives"
+    );
+}
+
 impl Parse for KebabIdentOrStr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if let Some(str) = rollback_err(input, |input| <syn::LitStr as Parse>::parse(input)) {
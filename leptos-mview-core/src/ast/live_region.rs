@@ -0,0 +1,65 @@
+use proc_macro2::Span;
+use proc_macro_error2::emit_error;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote_spanned,
+};
+
+use super::{attribute::kv::KvAttr, Attr, Element, KebabIdent, Tag, Value};
+use crate::{kw, parse};
+
+/// The `live_region` construct, a `div` that announces changes to its
+/// children to screen readers.
+///
+/// ```text
+/// live_region(polite) ( {message} )
+/// ```
+/// Expands to the same as:
+/// ```text
+/// div aria-live="polite" aria-atomic="true" ( {message} )
+/// ```
+///
+/// The politeness must be one of `polite`, `assertive` or `off`. Selectors
+/// and any other attributes are passed on to the `div`.
+pub struct LiveRegion(Element);
+
+const POLITENESS: [&str; 3] = ["polite", "assertive", "off"];
+
+impl Parse for LiveRegion {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let kw = kw::live_region::parse(input)?;
+        let (_, politeness) = parse::parenthesized::<syn::Ident>(input)?;
+        if !POLITENESS.iter().any(|p| politeness == p) {
+            emit_error!(
+                politeness.span(), "invalid live region politeness";
+                help = "expected one of `polite`, `assertive` or `off`"
+            );
+        }
+
+        let mut element = Element::parse_with_tag(div_tag(kw.span), input)?;
+        let attrs = element.attrs_mut();
+        attrs.push(aria_attr(
+            parse_quote_spanned! { kw.span=> aria-live },
+            &politeness.to_string(),
+            politeness.span(),
+        ));
+        attrs.push(aria_attr(
+            parse_quote_spanned! { kw.span=> aria-atomic },
+            "true",
+            kw.span,
+        ));
+
+        Ok(Self(element))
+    }
+}
+
+fn div_tag(span: Span) -> Tag { Tag::Html(syn::Ident::new("div", span)) }
+
+fn aria_attr(key: KebabIdent, value: &str, span: Span) -> Attr {
+    let value = Value::Lit(syn::LitStr::new(value, span).into());
+    Attr::Kv(KvAttr::new(key, value))
+}
+
+impl LiveRegion {
+    pub fn into_element(self) -> Element { self.0 }
+}
@@ -0,0 +1,134 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use proc_macro_error2::emit_error;
+use quote::{quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use super::{Children, Value};
+use crate::{
+    expand::root_children_tokens,
+    parse::{self, rollback_err},
+};
+
+/// The `match` construct, which renders the children of the arm that matches.
+///
+/// ```text
+/// match {state} {
+///     State::Loading => ( "Loading..." )
+///     State::Done(n) => ( h1("Done") p({n}) )
+/// }
+/// ```
+/// Expands to the same as:
+/// ```text
+/// match state {
+///     State::Loading => mview! { "Loading..." }.into_any(),
+///     State::Done(n) => mview! { h1("Done") p({n}) }.into_any(),
+/// }
+/// ```
+///
+/// Each arm can have any number of children, which are rendered as a
+/// fragment. Arms can be separated with commas, and patterns can have guards
+/// (`n if n > 3 => (...)`). If the value is a bracket (like
+/// `[state.get()]`), the whole match is wrapped in a `move ||` closure and
+/// will rerender when the value changes.
+pub struct MatchView {
+    match_token: Token![match],
+    value: Value,
+    arms: Vec<(TokenStream, Children)>,
+}
+
+impl Parse for MatchView {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let match_token = <Token![match]>::parse(input)?;
+        let value = Value::parse(input)?;
+        let (_, inner) = parse::extract_braced(input)?;
+
+        let mut arms = Vec::new();
+        while !inner.is_empty() {
+            // the pattern is everything up to the `=>`
+            let mut pattern = TokenStream::new();
+            while !inner.peek(Token![=>]) {
+                if inner.is_empty() {
+                    return Err(inner.error("expected `=>` after the pattern"));
+                }
+                pattern.extend([TokenTree::parse(&inner)?]);
+            }
+            if pattern.is_empty() {
+                return Err(inner.error("expected a pattern"));
+            }
+            <Token![=>]>::parse(&inner)?;
+
+            let children = if inner.peek(syn::token::Brace) {
+                parse::braced::<Children>(&inner)?.1
+            } else if inner.peek(syn::token::Paren) {
+                parse::parenthesized::<Children>(&inner)?.1
+            } else {
+                return Err(inner.error("expected children block in braces or parens"));
+            };
+            for slot in children.slot_children() {
+                emit_error!(slot.tag().span(), "slots are not supported inside `match`");
+            }
+            for (name, _) in children.prop_children() {
+                emit_error!(name.span(), "view props are not supported inside `match`");
+            }
+            arms.push((pattern, children));
+
+            // commas between arms are optional
+            rollback_err(&inner, <Token![,]>::parse);
+        }
+
+        Ok(Self {
+            match_token,
+            value,
+            arms,
+        })
+    }
+}
+
+impl MatchView {
+    /// Converts this match into a block value, to be used as a child.
+    pub fn into_value(self) -> Value {
+        let span = self.match_token.span;
+        Value::Block {
+            tokens: self.into_token_stream(),
+            braces: syn::token::Brace(span),
+        }
+    }
+}
+
+impl ToTokens for MatchView {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let span = self.match_token.span;
+        let match_token = self.match_token;
+
+        // a bracket without a prefix reruns the whole match
+        let (value, is_reactive) = match &self.value {
+            Value::Bracket {
+                tokens,
+                prefixes: None,
+                ..
+            } => (quote_spanned! { span=> {#tokens} }, true),
+            other => (other.to_token_stream(), false),
+        };
+
+        let arms = self.arms.iter().map(|(pattern, children)| {
+            let children = root_children_tokens(children.node_children(), Span::call_site());
+            quote_spanned! { span=>
+                #pattern => ::leptos::prelude::IntoAny::into_any(#children),
+            }
+        });
+        let view = quote_spanned! { span=>
+            #match_token #value {
+                #(#arms)*
+            }
+        };
+
+        tokens.extend(if is_reactive {
+            quote_spanned! { span=> move || { #view } }
+        } else {
+            quote_spanned! { span=> { #view } }
+        });
+    }
+}
@@ -0,0 +1,65 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use crate::kw;
+
+/// A context value provided at the start of the macro.
+///
+/// ```text
+/// provide: Theme::Dark;
+/// ```
+/// Expands to:
+/// ```text
+/// provide_context(Theme::Dark);
+/// ```
+///
+/// The value is any expression up to the `;`. The context is provided to the
+/// current owner before the rest of the view is built, so any components in
+/// the view can read it with `use_context`.
+pub struct Provide {
+    provide_token: kw::provide,
+    value: TokenStream,
+}
+
+impl Parse for Provide {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let provide_token = kw::provide::parse(input)?;
+        <Token![:]>::parse(input)?;
+
+        let mut value = TokenStream::new();
+        while !input.is_empty() && !input.peek(Token![;]) {
+            value.extend([TokenTree::parse(input)?]);
+        }
+        if value.is_empty() {
+            return Err(input.error("expected a value to provide"));
+        }
+        <Token![;]>::parse(input)?;
+
+        Ok(Self {
+            provide_token,
+            value,
+        })
+    }
+}
+
+impl Provide {
+    /// Whether the input starts with a `provide:` directive.
+    ///
+    /// A component path like `provide::Component` is not a directive.
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(kw::provide) && input.peek2(Token![:]) && !input.peek2(Token![::])
+    }
+}
+
+impl ToTokens for Provide {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let value = &self.value;
+        tokens.extend(quote_spanned! { self.provide_token.span=>
+            ::leptos::prelude::provide_context(#value);
+        });
+    }
+}
@@ -0,0 +1,108 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error2::emit_error;
+use quote::quote_spanned;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote_spanned,
+};
+
+use super::{
+    attribute::kv::KvAttr, Attr, Child, Children, Element, KebabIdent, NodeChild, Tag, Value,
+};
+use crate::{expand::root_children_tokens, kw, parse};
+
+/// The `resource` construct, a shorthand for reading a resource inside a
+/// Leptos `Suspense`.
+///
+/// ```text
+/// resource {user} loading=["Loading..."] |user| ( p({user.name}) )
+/// ```
+/// Expands to the same as:
+/// ```text
+/// Suspense fallback=["Loading..."] {
+///     {move || {
+///         let resource = user.clone();
+///         Suspend::new(async move {
+///             (|user| mview! { p({user.name}) })(resource.await)
+///         })
+///     }}
+/// }
+/// ```
+///
+/// The closure arguments are optional, if the data is not needed. Any other
+/// attributes are passed on to the `Suspense`.
+pub struct ResourceView(Element);
+
+impl Parse for ResourceView {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let kw = kw::resource::parse(input)?;
+        let (_, resource) = parse::braced_tokens(input)?;
+        let element = Element::parse_with_tag(suspense_tag(kw.span), input)?;
+
+        let children = element.children().map_or_else(
+            || {
+                emit_error!(
+                    kw.span, "expected children to render once the resource has loaded";
+                    help = "add children after the attributes: `resource {{res}} |data| (...)`"
+                );
+                TokenStream::new()
+            },
+            |children| {
+                for slot in children.slot_children() {
+                    emit_error!(
+                        slot.tag().span(),
+                        "slots are not supported inside `resource`"
+                    );
+                }
+                for (name, _) in children.prop_children() {
+                    emit_error!(
+                        name.span(),
+                        "view props are not supported inside `resource`"
+                    );
+                }
+                root_children_tokens(children.node_children(), Span::call_site())
+            },
+        );
+
+        let render = element.children_args().map_or_else(
+            || quote_spanned! { kw.span=> { _ = __mview_resource.await; #children } },
+            |args| quote_spanned! { kw.span=> (#args #children)(__mview_resource.await) },
+        );
+        let suspended = Value::Block {
+            tokens: quote_spanned! { kw.span=>
+                move || {
+                    let __mview_resource = ::std::clone::Clone::clone(&(#resource));
+                    ::leptos::prelude::Suspend::new(async move { #render })
+                }
+            },
+            braces: syn::token::Brace(kw.span),
+        };
+
+        let mut attrs = element.attrs().clone();
+        for attr in attrs.iter_mut() {
+            let Attr::Kv(kv) = attr else { continue };
+            if kv.key().repr() == "loading" {
+                let fallback = syn::Ident::new("fallback", kv.key().span());
+                *kv = KvAttr::new(KebabIdent::from(fallback), kv.value().clone());
+            }
+        }
+
+        Ok(Self(Element::new(
+            suspense_tag(kw.span),
+            element.selectors().clone(),
+            attrs,
+            None,
+            Some(Children::new(vec![Child::Node(NodeChild::Value(
+                suspended,
+            ))])),
+        )))
+    }
+}
+
+fn suspense_tag(span: Span) -> Tag {
+    Tag::Component(parse_quote_spanned! { span=> ::leptos::prelude::Suspense })
+}
+
+impl ResourceView {
+    pub fn into_element(self) -> Element { self.0 }
+}
@@ -0,0 +1,131 @@
+use proc_macro2::Span;
+use proc_macro_error2::emit_error;
+use quote::quote_spanned;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote_spanned,
+};
+
+use super::{
+    attribute::{directive::Directive, kv::KvAttr},
+    Attr, Child, Children, Element, KebabIdentOrStr, NodeChild, Tag, Value,
+};
+use crate::{kw, parse};
+
+/// The `show_hidden` construct, which hides its children with the `hidden`
+/// attribute instead of unmounting them.
+///
+/// ```text
+/// show_hidden [open.get()] (
+///     input type="text";
+///     Details;
+/// )
+/// ```
+/// Expands to the same as:
+/// ```text
+/// input type="text" hidden=[!open.get()];
+/// Details attr:hidden=[!open.get()];
+/// ```
+///
+/// The condition can be a bracket (like `[open.get()]`) or a block with
+/// something to call (like `{is_open}`), the same as `Show`'s `when`. Every
+/// child must be an element or a component, as text cannot be hidden. The
+/// children are rendered as if they were written directly in the parent.
+pub struct ShowHidden {
+    children: Children,
+}
+
+impl Parse for ShowHidden {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let kw = kw::show_hidden::parse(input)?;
+        let when = Value::parse(input)?;
+        let hidden = negate(when, kw.span);
+
+        let mut children = if input.peek(syn::token::Brace) {
+            parse::braced::<Children>(input)?.1
+        } else {
+            parse::parenthesized::<Children>(input)?.1
+        };
+        for child in children.iter_mut() {
+            match child {
+                Child::Node(NodeChild::Element(element)) => add_hidden(element, &hidden),
+                Child::Node(node) => emit_error!(
+                    node.span(), "only elements can be hidden by `show_hidden`";
+                    help = "wrap this in an element, like `span({value})`"
+                ),
+                Child::Slot(_, element) => {
+                    emit_error!(
+                        element.tag().span(),
+                        "slots are not supported inside `show_hidden`"
+                    );
+                }
+                Child::Prop(name, _) => {
+                    emit_error!(
+                        name.span(),
+                        "view props are not supported inside `show_hidden`"
+                    );
+                }
+            }
+        }
+
+        Ok(Self { children })
+    }
+}
+
+impl ShowHidden {
+    /// Whether the input starts with a `show_hidden` construct.
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(kw::show_hidden)
+            && (input.peek2(syn::token::Brace) || input.peek2(syn::token::Bracket))
+    }
+
+    pub fn into_children(self) -> Children { self.children }
+}
+
+/// Negates the condition to get the value of the `hidden` attribute, in the
+/// same way as `!{when}` or `![when]`.
+fn negate(when: Value, span: Span) -> Value {
+    let tokens = match when {
+        Value::Block { tokens, braces } => {
+            quote_spanned! { braces.span.join()=> !(#tokens)() }
+        }
+        Value::Bracket {
+            tokens,
+            brackets,
+            prefixes: None,
+        } => quote_spanned! { brackets.span.join()=> !{#tokens} },
+        other => {
+            emit_error!(
+                other.span(), "expected a bracket or block condition";
+                help = "use a bracket like `[open.get()]` or a block like `{is_open}`"
+            );
+            quote_spanned! { other.span()=> false }
+        }
+    };
+    Value::Bracket {
+        tokens,
+        brackets: syn::token::Bracket(span),
+        prefixes: None,
+    }
+}
+
+fn add_hidden(element: &mut Element, hidden: &Value) {
+    let span = element.tag().span();
+    let attr = if matches!(element.tag(), Tag::Component(_)) {
+        // forwarded to the root element(s) of the component
+        Attr::Directive(Directive {
+            dir: syn::Ident::new("attr", span),
+            key: KebabIdentOrStr::KebabIdent(parse_quote_spanned! { span=> hidden }),
+            modifier: None,
+            value: Some(hidden.clone()),
+            optional: None,
+            target_type: None,
+        })
+    } else {
+        Attr::Kv(KvAttr::new(
+            parse_quote_spanned! { span=> hidden },
+            hidden.clone(),
+        ))
+    };
+    element.attrs_mut().push(attr);
+}
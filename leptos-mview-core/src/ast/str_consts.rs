@@ -0,0 +1,101 @@
+use std::cell::RefCell;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+
+/// A string literal value that is used in the macro.
+struct StrLit {
+    value: String,
+    /// How many times the literal is parsed as a value.
+    count: usize,
+    /// Whether a `const` has been used in place of the literal.
+    used: bool,
+}
+
+thread_local! {
+    /// The string literal values in the macro that is being parsed.
+    static STR_LITS: RefCell<Vec<StrLit>> = RefCell::default();
+}
+
+/// Clears the literals from any previous macro call. Must be called before
+/// parsing the macro input.
+pub fn reset_str_consts() { STR_LITS.take(); }
+
+/// Counts a string literal that is parsed as a [`Value`](super::Value).
+pub fn count_str_lit(lit: &syn::LitStr) {
+    let value = lit.value();
+    STR_LITS.with_borrow_mut(|lits| {
+        if let Some(existing) = lits.iter_mut().find(|existing| existing.value == value) {
+            existing.count += 1;
+        } else {
+            lits.push(StrLit {
+                value,
+                count: 1,
+                used: false,
+            });
+        }
+    });
+}
+
+/// The name of the `const` holding the `index`th literal.
+///
+/// Uses are spanned to the literal for better error messages, both the literal
+/// and the `const` are from the macro call so they resolve the same.
+fn const_name(index: usize, span: Span) -> syn::Ident {
+    format_ident!("__MVIEW_STR_{}", index, span = span)
+}
+
+/// Expands a string literal value, using a shared `const` instead if the same
+/// literal is used more than once in the macro.
+///
+/// ```text
+/// li class="item" ("one")
+/// li class="item" ("two")
+/// ```
+/// Expands to:
+/// ```text
+/// const __MVIEW_STR_0: &str = "item";
+/// li class={__MVIEW_STR_0} ("one")
+/// li class={__MVIEW_STR_0} ("two")
+/// ```
+pub fn str_lit_tokens(lit: &syn::LitStr) -> TokenStream {
+    let value = lit.value();
+    STR_LITS.with_borrow_mut(|lits| {
+        match lits
+            .iter_mut()
+            .enumerate()
+            .find(|(_, existing)| existing.value == value && existing.count > 1)
+        {
+            Some((index, existing)) => {
+                existing.used = true;
+                const_name(index, lit.span()).into_token_stream()
+            }
+            None => lit.to_token_stream(),
+        }
+    })
+}
+
+/// `const` items for every literal used by [`str_lit_tokens`], or `None` if
+/// there are none.
+///
+/// Must be called after the rest of the view has been expanded.
+pub fn str_const_tokens() -> Option<TokenStream> {
+    STR_LITS.with_borrow(|lits| {
+        let span = Span::call_site();
+        let consts = lits
+            .iter()
+            .enumerate()
+            .filter(|(_, lit)| lit.used)
+            .map(|(index, lit)| {
+                let name = const_name(index, span);
+                let value = &lit.value;
+                quote_spanned! { span=> const #name: &::std::primitive::str = #value; }
+            })
+            .collect::<Vec<_>>();
+        if consts.is_empty() {
+            None
+        } else {
+            Some(quote! { #(#consts)* })
+        }
+    })
+}
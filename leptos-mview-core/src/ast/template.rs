@@ -0,0 +1,50 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use super::Children;
+use crate::{kw, parse};
+
+/// A named, reusable view defined at the start of the macro.
+///
+/// ```text
+/// template greeting = ( "Hi " strong("there") );
+/// p(@greeting) p(@greeting)
+/// ```
+///
+/// Templates are expanded to a closure `let greeting = || ...;` before the
+/// rest of the view, and `@greeting` calls this closure.
+///
+/// Only parenthesized children are accepted, to avoid conflicting with a HTML
+/// `<template>` element with a block attribute (like `template attr={...}`).
+pub struct Template {
+    name: syn::Ident,
+    children: Children,
+}
+
+impl Parse for Template {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        kw::template::parse(input)?;
+        let name = syn::Ident::parse(input)?;
+        <Token![=]>::parse(input)?;
+        let (_, children) = parse::parenthesized::<Children>(input)?;
+        <Token![;]>::parse(input)?;
+        Ok(Self { name, children })
+    }
+}
+
+impl Template {
+    /// Whether the input starts with a template definition.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.parse::<kw::template>().is_ok()
+            && fork.parse::<syn::Ident>().is_ok()
+            && fork.parse::<Token![=]>().is_ok()
+            && fork.peek(syn::token::Paren)
+    }
+
+    pub const fn name(&self) -> &syn::Ident { &self.name }
+
+    pub fn into_children(self) -> Children { self.children }
+}
@@ -1,14 +1,22 @@
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream, TokenTree};
+#[cfg(feature = "lints")]
+use proc_macro_error2::emit_warning;
 use proc_macro_error2::{emit_error, Diagnostic};
-use quote::{quote, quote_spanned, ToTokens};
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::{
     ext::IdentExt,
-    parse::{Parse, ParseStream},
+    parse::{Parse, ParseStream, Parser},
     parse_quote,
+    punctuated::Punctuated,
     spanned::Spanned,
+    Token,
 };
 
-use crate::parse::{self, rollback_err};
+use crate::{
+    ast::{count_str_lit, parse_id_ref, str_lit_tokens, KebabIdent},
+    parse::{self, rollback_err},
+    span,
+};
 
 /// Interpolated Rust expressions within the macro.
 ///
@@ -16,8 +24,16 @@ use crate::parse::{self, rollback_err};
 ///
 /// Expressions within brackets are wrapped in a closure, e.g. `[!is_red.get()]`
 /// is expanded to `{move || !is_red.get()}`.
+/// A bracket with a pattern and `=>` takes an argument instead:
+/// `[ev => log!("{ev:?}")]` is expanded to `{move |ev| { log!("{ev:?}") }}`.
+///
+/// Only literals and inline `if` expressions can have no delimiter, to avoid
+/// ambiguity. `if a { b } else { c }` is treated the same as the bracketed
+/// `[if a { b } else { c }]`.
 ///
-/// Only literals can have no delimiter, to avoid ambiguity.
+/// A `!` before a block or bracket negates it in a closure: `!{is_visible}`
+/// is expanded to `{move || !is_visible()}`, and `![a.get()]` to
+/// `{move || !a.get()}`.
 ///
 /// Block and bracketed expressions are not parsed as [`syn::Expr`]s as the
 /// specific details of what is contained is not required (they are expanded
@@ -45,13 +61,21 @@ impl Parse for Value {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if input.peek(syn::token::Bracket) {
             let (brackets, tokens) = parse::bracketed_tokens(input).unwrap();
+            if let Some((pat, body)) = split_closure_arm(&tokens) {
+                let span = brackets.span.join();
+                return Ok(Self::Block {
+                    tokens: quote_spanned! { span=> move |#pat| { #body } },
+                    braces: syn::token::Brace(span),
+                });
+            }
             Ok(Self::Bracket {
                 tokens,
                 brackets,
                 prefixes: None,
             })
         // with prefixes like `f["{}", something]`
-        } else if input.peek(syn::Ident::peek_any) && input.peek2(syn::token::Bracket) {
+        // (keywords are never prefixes, so that `match [value] {...}` works)
+        } else if input.peek(syn::Ident) && input.peek2(syn::token::Bracket) {
             let prefixes = syn::Ident::parse_any(input).unwrap();
             let (brackets, tokens) = parse::bracketed_tokens(input).unwrap();
             Ok(Self::Bracket {
@@ -59,11 +83,50 @@ impl Parse for Value {
                 brackets,
                 prefixes: Some(prefixes),
             })
+        // negated `!{getter}` or `![value]`
+        } else if input.peek(Token![!])
+            && (input.peek2(syn::token::Brace) || input.peek2(syn::token::Bracket))
+        {
+            let not = <Token![!]>::parse(input).unwrap();
+            let (tokens, span) = if input.peek(syn::token::Brace) {
+                let (braces, tokens) = parse::braced_tokens(input).unwrap();
+                (
+                    quote_spanned! { braces.span.join()=> (#tokens)() },
+                    braces.span.join(),
+                )
+            } else {
+                let (brackets, tokens) = parse::bracketed_tokens(input).unwrap();
+                (
+                    quote_spanned! { brackets.span.join()=> {#tokens} },
+                    brackets.span.join(),
+                )
+            };
+            Ok(Self::Bracket {
+                tokens: quote! { #not #tokens },
+                brackets: syn::token::Bracket(span::join(not.span, span)),
+                prefixes: None,
+            })
+        // a reference to a generated id, `@ref(name)`
+        } else if input.peek(Token![@]) && input.peek2(Token![ref]) {
+            parse_id_ref(input)
+        // inline `if a { b } else { c }`, same as `[if a { b } else { c }]`
+        } else if input.peek(Token![if]) {
+            let span = input.span();
+            let tokens = parse_if_tokens(input)?;
+            let span = span::join(span, tokens.span());
+            Ok(Self::Bracket {
+                tokens,
+                brackets: syn::token::Bracket(span),
+                prefixes: None,
+            })
         } else if input.peek(syn::token::Brace) {
             let (braces, tokens) = parse::braced_tokens(input).unwrap();
             Ok(Self::Block { tokens, braces })
         } else if input.peek(syn::Lit) {
             let lit = syn::Lit::parse(input).unwrap();
+            if let syn::Lit::Str(s) = &lit {
+                count_str_lit(s);
+            }
             Ok(Self::Lit(lit))
         } else {
             Err(input.error("invalid value: expected bracket, block or literal"))
@@ -71,9 +134,75 @@ impl Parse for Value {
     }
 }
 
+/// Splits the tokens of a bracket like `[ev => body]` into the closure
+/// argument pattern and the body.
+///
+/// Returns `None` if there is no `=>` outside of any delimiters, which is
+/// never valid in a normal bracket.
+fn split_closure_arm(tokens: &TokenStream) -> Option<(TokenStream, TokenStream)> {
+    let tokens = tokens.clone().into_iter().collect::<Vec<_>>();
+    let arrow = tokens.windows(2).position(|pair| match pair {
+        [TokenTree::Punct(eq), TokenTree::Punct(gt)] => {
+            eq.as_char() == '=' && eq.spacing() == Spacing::Joint && gt.as_char() == '>'
+        }
+        _ => false,
+    })?;
+
+    let (pat, body) = (&tokens[..arrow], &tokens[arrow + 2..]);
+    if pat.is_empty() {
+        emit_error!(tokens[arrow].span(), "expected a pattern before `=>`");
+    }
+    let pat = match pat {
+        // `(n) => ...` would warn about unnecessary parentheses around the
+        // argument, but `(a, b)` is a tuple
+        [TokenTree::Group(group)]
+            if group.delimiter() == Delimiter::Parenthesis
+                && !group
+                    .stream()
+                    .into_iter()
+                    .any(|tt| matches!(tt, TokenTree::Punct(p) if p.as_char() == ',')) =>
+        {
+            group.stream()
+        }
+        _ => pat.iter().cloned().collect(),
+    };
+    Some((pat, body.iter().cloned().collect()))
+}
+
+/// Takes the tokens of an `if` expression, including any `else if` or `else`
+/// branches.
+///
+/// The condition is taken as-is up to the first block, the same as how Rust
+/// parses conditions (struct literals are not allowed there).
+pub fn parse_if_tokens(input: ParseStream) -> syn::Result<TokenStream> {
+    let mut tokens = <Token![if]>::parse(input)?.into_token_stream();
+    loop {
+        if input.peek(syn::token::Brace) {
+            break;
+        } else if input.is_empty() || input.peek(Token![;]) {
+            return Err(input.error("expected block after `if` condition"));
+        }
+        tokens.append(TokenTree::parse(input)?);
+    }
+    tokens.append(TokenTree::parse(input)?);
+
+    if input.peek(Token![else]) {
+        <Token![else]>::parse(input)?.to_tokens(&mut tokens);
+        if input.peek(Token![if]) {
+            tokens.extend(parse_if_tokens(input)?);
+        } else if input.peek(syn::token::Brace) {
+            tokens.append(TokenTree::parse(input)?);
+        } else {
+            return Err(input.error("expected block or `if` after `else`"));
+        }
+    }
+    Ok(tokens)
+}
+
 impl ToTokens for Value {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         tokens.extend(match self {
+            Self::Lit(syn::Lit::Str(s)) => str_lit_tokens(s),
             Self::Lit(lit) => lit.into_token_stream(),
             // using the tokens as the span instead of the block provides better error messages
             // see test ui/errors/invalid_child
@@ -87,18 +216,8 @@ impl ToTokens for Value {
                 prefixes,
                 brackets,
             } => {
-                if let Some(prefixes) = prefixes {
-                    // only f[] is supported for now
-                    if prefixes == "f" {
-                        let format = quote_spanned!(prefixes.span()=> format!);
-                        quote_spanned!(brackets.span.join()=> move || ::std::#format(#tokens))
-                    } else {
-                        emit_error!(
-                            prefixes.span(),
-                            "unsupported prefix: only `f` is supported."
-                        );
-                        quote! {}
-                    }
+                if let Some(prefix) = prefixes {
+                    bracket_prefix_tokens(prefix, tokens, brackets.span.join())
                 } else {
                     quote_spanned!(brackets.span.join()=> move || {#tokens})
                 }
@@ -107,6 +226,203 @@ impl ToTokens for Value {
     }
 }
 
+/// Signature of a function that expands a prefixed bracket, like `f[...]`.
+///
+/// Takes the prefix, the tokens inside the brackets, and the span of the
+/// brackets.
+type BracketPrefixExpander = fn(&syn::Ident, &TokenStream, Span) -> TokenStream;
+
+/// All supported bracket prefixes and their expansions.
+///
+/// Add new prefixes here, they will be picked up by the [`Value`] expansion
+/// and included in the error message for unknown prefixes.
+const BRACKET_PREFIXES: &[(&str, BracketPrefixExpander)] = &[
+    // `f["{}", a]` => `move || format!("{}", a)`
+    ("f", |prefix, tokens, span| {
+        let format = quote_spanned!(prefix.span()=> format!);
+        quote_spanned!(span=> move || ::std::#format(#tokens))
+    }),
+    // `p["{}", a]` => `move || { leptos::logging::log!("{}", a); }`
+    // (`move |_| ...` for event handlers, see `Value::to_handler`)
+    ("p", |prefix, tokens, span| {
+        let log = quote_spanned!(prefix.span()=> log!);
+        quote_spanned!(span=> move || { ::leptos::logging::#log(#tokens); })
+    }),
+    // `classes["a", b => cond]` => `move || "a b"` (if cond is true)
+    ("classes", classes_prefix_tokens),
+    // `s["display: flex", color: c]` => `move || "display: flex; color: red"`
+    ("s", style_prefix_tokens),
+];
+
+/// An entry in a `classes[...]` bracket.
+enum ClassEntry {
+    /// A class (or space-separated classes) that is always added.
+    Always(TokenStream),
+    /// A `name => condition` class that is only added if the condition is true.
+    When(syn::LitStr, TokenStream),
+}
+
+impl Parse for ClassEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = rollback_err(input, |input| {
+            let name = parse_entry_name(input)?;
+            <Token![=>]>::parse(input)?;
+            Ok(name)
+        });
+        let tokens = parse_entry_value(input, "expected class")?;
+
+        Ok(match name {
+            Some(name) => Self::When(name, tokens),
+            None => Self::Always(tokens),
+        })
+    }
+}
+
+/// An entry in an `s[...]` bracket.
+enum StyleEntry {
+    /// Style declarations (like `"display: flex"`) that are always added.
+    Always(TokenStream),
+    /// A `property: value` declaration.
+    Property(syn::LitStr, TokenStream),
+}
+
+impl Parse for StyleEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = rollback_err(input, |input| {
+            let name = parse_entry_name(input)?;
+            if input.peek(Token![::]) {
+                return Err(input.error("expected `:`"));
+            }
+            <Token![:]>::parse(input)?;
+            Ok(name)
+        });
+        let tokens = parse_entry_value(input, "expected style")?;
+
+        Ok(match name {
+            Some(name) => Self::Property(name, tokens),
+            None => Self::Always(tokens),
+        })
+    }
+}
+
+/// Parses the name of a `classes[...]` or `s[...]` entry, which is either a
+/// string or a kebab-case identifier.
+fn parse_entry_name(input: ParseStream) -> syn::Result<syn::LitStr> {
+    if input.peek(syn::LitStr) {
+        input.parse::<syn::LitStr>()
+    } else {
+        let ident = KebabIdent::parse(input)?;
+        Ok(syn::LitStr::new(ident.repr(), ident.span()))
+    }
+}
+
+/// Takes all the tokens up to the next comma, or the end of the input.
+fn parse_entry_value(input: ParseStream, expected: &str) -> syn::Result<TokenStream> {
+    let mut tokens = TokenStream::new();
+    while !input.is_empty() && !input.peek(Token![,]) {
+        tokens.append(TokenTree::parse(input)?);
+    }
+    if tokens.is_empty() {
+        return Err(input.error(expected));
+    }
+    Ok(tokens)
+}
+
+/// Expands a `classes["base", active => is_active]` bracket to a closure
+/// that joins all the enabled classes with spaces.
+fn classes_prefix_tokens(_prefix: &syn::Ident, tokens: &TokenStream, span: Span) -> TokenStream {
+    let Some(entries) = parse_entries::<ClassEntry>(tokens) else {
+        return quote! {};
+    };
+
+    let pushes = entries.into_iter().map(|entry| match entry {
+        ClassEntry::Always(class) => quote! {
+            push(::std::convert::AsRef::<str>::as_ref(&(#class)));
+        },
+        ClassEntry::When(name, cond) => quote! {
+            if #cond {
+                push(#name);
+            }
+        },
+    });
+
+    quote_spanned! { span=>
+        move || {
+            let mut classes = ::std::string::String::new();
+            let mut push = |class: &str| {
+                if !classes.is_empty() && !class.is_empty() {
+                    classes.push(' ');
+                }
+                classes.push_str(class);
+            };
+            #(#pushes)*
+            classes
+        }
+    }
+}
+
+/// Expands a bracket with a prefix, emitting an error if the prefix is not
+/// in [`BRACKET_PREFIXES`].
+fn bracket_prefix_tokens(prefix: &syn::Ident, tokens: &TokenStream, span: Span) -> TokenStream {
+    let name = prefix.unraw().to_string();
+    if let Some((_, expand)) = BRACKET_PREFIXES.iter().find(|(p, _)| *p == name) {
+        expand(prefix, tokens, span)
+    } else {
+        let supported = BRACKET_PREFIXES
+            .iter()
+            .map(|(p, _)| format!("`{p}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        emit_error!(
+            prefix.span(),
+            "unsupported prefix: supported prefixes are {}",
+            supported
+        );
+        quote! {}
+    }
+}
+
+/// Expands an `s["display: flex", color: c]` bracket to a closure that joins
+/// all the style declarations with `; `.
+fn style_prefix_tokens(_prefix: &syn::Ident, tokens: &TokenStream, span: Span) -> TokenStream {
+    let Some(entries) = parse_entries::<StyleEntry>(tokens) else {
+        return quote! {};
+    };
+
+    let pushes = entries.into_iter().map(|entry| match entry {
+        StyleEntry::Always(style) => quote! {
+            push(::std::format_args!("{}", #style));
+        },
+        StyleEntry::Property(name, value) => quote! {
+            push(::std::format_args!("{}: {}", #name, #value));
+        },
+    });
+
+    quote_spanned! { span=>
+        move || {
+            let mut style = ::std::string::String::new();
+            let mut push = |declaration: ::std::fmt::Arguments| {
+                if !style.is_empty() {
+                    style.push_str("; ");
+                }
+                _ = ::std::fmt::Write::write_fmt(&mut style, declaration);
+            };
+            #(#pushes)*
+            style
+        }
+    }
+}
+
+/// Parses the comma-separated entries of a prefixed bracket, emitting an
+/// error if they are invalid.
+fn parse_entries<T: Parse>(tokens: &TokenStream) -> Option<Punctuated<T, Token![,]>> {
+    let parser = Punctuated::<T, Token![,]>::parse_terminated;
+    parser
+        .parse2(tokens.clone())
+        .map_err(|e| emit_error!(e.span(), "{}", e))
+        .ok()
+}
+
 impl Value {
     /// Returns the [`Span`] of this [`Value`].
     ///
@@ -119,6 +435,51 @@ impl Value {
         }
     }
 
+    /// Converts this value to be used as an event handler.
+    ///
+    /// A `p[...]` bracket ignores the event instead of taking no arguments,
+    /// so that `on:click=p["clicked"]` works.
+    pub fn to_handler(&self) -> Self {
+        match self {
+            Self::Bracket {
+                tokens,
+                brackets,
+                prefixes: Some(prefix),
+            } if prefix == "p" => {
+                let span = brackets.span.join();
+                let log = quote_spanned!(prefix.span()=> log!);
+                Self::Block {
+                    tokens: quote_spanned!(span=> move |_| { ::leptos::logging::#log(#tokens); }),
+                    braces: syn::token::Brace(span),
+                }
+            }
+            other => other.clone(),
+        }
+    }
+
+    /// Warns if this is a bracket containing only a literal, like `["static"]`.
+    ///
+    /// The bracket adds a `move ||` closure that is never needed, so the
+    /// literal can be used directly instead.
+    #[cfg(feature = "lints")]
+    pub fn warn_if_bracketed_lit(&self) {
+        let Self::Bracket {
+            tokens,
+            brackets,
+            prefixes: None,
+        } = self
+        else {
+            return;
+        };
+        let Ok(lit) = syn::parse2::<syn::Lit>(tokens.clone()) else {
+            return;
+        };
+        emit_warning!(
+            brackets.span.join(), "unnecessary brackets around a literal";
+            help = "use the literal directly: `{}`", lit.to_token_stream()
+        );
+    }
+
     /// Either parses a valid [`Value`], or inserts a `MissingValueAfterEq`
     /// never-type enum.
     pub fn parse_or_emit_err(input: ParseStream, fallback_span: Span) -> Self {
@@ -198,6 +559,8 @@ mod tests {
         exprs.insert("[abc.get()]", ValueKind::Bracket);
         exprs.insert("{(aa,)}", ValueKind::Block);
         exprs.insert("[{a; b}]", ValueKind::Bracket);
+        exprs.insert("if a { b } else { c }", ValueKind::Bracket);
+        exprs.insert("if a.get() { b } else if c { d }", ValueKind::Bracket);
 
         for (expr, kind) in exprs {
             let value = syn::parse_str(expr).unwrap();
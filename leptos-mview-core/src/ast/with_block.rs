@@ -0,0 +1,155 @@
+use proc_macro_error2::emit_error;
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use super::{
+    attribute::directive::Directive, Attr, Child, Children, Element, KebabIdentOrStr, NodeChild,
+    Tag, Value,
+};
+use crate::{
+    expand::tokens_contain_ident,
+    kw,
+    parse::{self, rollback_err},
+};
+
+/// A block of children that share some `clone:` directives.
+///
+/// ```text
+/// with { clone: a; clone: b; } (
+///     Show when=[true] { {a.clone()} Show when=[true] { {b.clone()} } }
+/// )
+/// ```
+///
+/// The clones are added to every component and slot inside the children that
+/// uses them, including nested ones, as if `clone:a clone:b` was written on
+/// each of them. The block itself renders the children as if they were written
+/// directly in the parent.
+pub struct WithBlock {
+    children: Children,
+}
+
+impl Parse for WithBlock {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        kw::with::parse(input)?;
+        let (_, inner) = parse::extract_braced(input)?;
+        let mut clones = Vec::new();
+        while !inner.is_empty() {
+            let directive = Directive::parse(&inner)?;
+            if directive.dir == "clone" {
+                clones.push(directive);
+            } else {
+                emit_error!(
+                    directive.dir.span(),
+                    "only `clone:` directives are supported in `with` blocks"
+                );
+            }
+            // separators are optional
+            rollback_err(&inner, <Token![;]>::parse);
+        }
+
+        let mut children = if input.peek(syn::token::Brace) {
+            parse::braced::<Children>(input)?.1
+        } else {
+            parse::parenthesized::<Children>(input)?.1
+        };
+        add_clones(&mut children, &clones);
+
+        Ok(Self { children })
+    }
+}
+
+impl WithBlock {
+    /// Whether the input starts with a `with { ... }` block.
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(kw::with) && input.peek2(syn::token::Brace)
+    }
+
+    pub fn into_children(self) -> Children { self.children }
+}
+
+fn add_clones(children: &mut Children, clones: &[Directive]) {
+    for child in children.iter_mut() {
+        match child {
+            Child::Node(NodeChild::Element(element)) | Child::Slot(_, element) => {
+                add_element_clones(element, clones);
+            }
+            Child::Prop(_, children) => add_clones(children, clones),
+            Child::Node(_) => (),
+        }
+    }
+}
+
+fn add_element_clones(element: &mut Element, clones: &[Directive]) {
+    // `clone:` is only supported on components and slots, HTML elements just
+    // need the clones to be added to their children.
+    if matches!(element.tag(), Tag::Component(_)) {
+        // only clone what is used, so that unused clones don't warn
+        for clone in clones {
+            if clone_name(clone).is_none_or(|name| element_uses_ident(element, &name)) {
+                element.attrs_mut().push(Attr::Directive(clone.clone()));
+            }
+        }
+    }
+    if let Some(children) = element.children_mut() {
+        add_clones(children, clones);
+    }
+}
+
+/// The name of the variable that a `clone:` directive creates, if it is valid.
+fn clone_name(clone: &Directive) -> Option<syn::Ident> {
+    match &clone.key {
+        KebabIdentOrStr::KebabIdent(ident) => Some(ident.to_snake_ident()),
+        KebabIdentOrStr::Field(field) => match &field.member {
+            syn::Member::Named(name) => Some(name.clone()),
+            syn::Member::Unnamed(_) => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether any value in the attributes or children of the element (including
+/// nested elements) uses the ident.
+fn element_uses_ident(element: &Element, ident: &syn::Ident) -> bool {
+    element
+        .attrs()
+        .iter()
+        .any(|attr| attr_uses_ident(attr, ident))
+        || element
+            .children()
+            .is_some_and(|children| children_use_ident(children, ident))
+}
+
+fn children_use_ident(children: &Children, ident: &syn::Ident) -> bool {
+    children.iter().any(|child| match child {
+        Child::Node(NodeChild::Value(value)) => value_uses_ident(value, ident),
+        Child::Node(NodeChild::Element(element)) | Child::Slot(_, element) => {
+            element_uses_ident(element, ident)
+        }
+        Child::Prop(_, children) => children_use_ident(children, ident),
+        Child::Node(NodeChild::Doctype(_)) => false,
+    })
+}
+
+fn attr_uses_ident(attr: &Attr, ident: &syn::Ident) -> bool {
+    match attr {
+        Attr::Kv(kv) => value_uses_ident(kv.value(), ident),
+        Attr::Directive(dir) => {
+            dir.value
+                .as_ref()
+                .is_some_and(|value| value_uses_ident(value, ident))
+                || matches!(&dir.key, KebabIdentOrStr::Dynamic(key) if value_uses_ident(key, ident))
+        }
+        Attr::Spread(spread) => tokens_contain_ident(spread.expr(), ident),
+        Attr::Struct(s) => s
+            .fields()
+            .iter()
+            .any(|kv| value_uses_ident(kv.value(), ident)),
+    }
+}
+
+fn value_uses_ident(value: &Value, ident: &syn::Ident) -> bool {
+    tokens_contain_ident(&value.to_token_stream(), ident)
+}
@@ -12,11 +12,15 @@ use syn::{ext::IdentExt, parse_quote, parse_quote_spanned, spanned::Spanned};
 
 use crate::ast::{
     attribute::{directive::Directive, selector::SelectorShorthand},
-    Attr, Element, KebabIdent, KebabIdentOrStr, NodeChild, Tag, Value,
+    Attr, Children, Element, KebabIdent, KebabIdentOrStr, NodeChild, Tag, Value,
 };
 
+/// Rendering static elements to HTML at compile time.
+mod inert;
+pub use inert::static_html;
 /// Functions for specific parts of an element's expansion.
 mod subroutines;
+pub use subroutines::tokens_contain_ident;
 #[allow(clippy::wildcard_imports)]
 use subroutines::*;
 /// Small helper functions for converting types or emitting errors.
@@ -112,11 +116,24 @@ pub fn xml_to_tokens(element: &Element) -> Option<TokenStream> {
         }
     };
 
+    // static classes from selectors and `class="..."` are merged into one call,
+    // which needs to be before any `class:` directives.
+    let static_class = xml_static_class_tokens(element);
+    let merge_classes = static_class.is_some();
+
     // add selector-style ids/classes (div.some-class #some-id)
-    let selector_methods = xml_selectors_tokens(element.selectors());
+    let selector_methods = xml_selectors_tokens(element.selectors(), merge_classes);
+
+    // The order of the methods determines the order of the rendered attributes.
+    // Key-value attributes keep their source order, so that the rendered HTML
+    // is deterministic and matches the macro input. Leptos always renders
+    // `class` and `style` last, so their position relative to other attributes
+    // does not matter.
 
     // parse normal attributes first
-    let mut attrs = TokenStream::new();
+    let mut attrs = static_class.unwrap_or_default();
+    #[cfg(feature = "source-locations")]
+    attrs.extend(xml_source_location_tokens(element.tag()));
     let mut spread_attrs = TokenStream::new();
     // put directives at the end so conditional attributes like `class:` work
     // with `class="..."` attributes
@@ -124,9 +141,14 @@ pub fn xml_to_tokens(element: &Element) -> Option<TokenStream> {
 
     for a in element.attrs().iter() {
         match a {
-            Attr::Kv(attr) => attrs.extend(xml_kv_attribute_tokens(attr, element.tag().kind())),
-            Attr::Directive(dir) => directives.extend(xml_directive_tokens(dir)),
+            Attr::Kv(attr) if merge_classes && static_class_lit(attr).is_some() => (),
+            Attr::Kv(attr) => attrs.extend(xml_kv_attribute_tokens(attr, element.tag())),
+            Attr::Directive(dir) => directives.extend(xml_directive_tokens(dir, element.tag())),
             Attr::Spread(spread) => spread_attrs.extend(xml_spread_tokens(spread)),
+            Attr::Struct(attr) => emit_error!(
+                attr.span(),
+                "struct attributes are only supported on components"
+            ),
         }
     }
 
@@ -187,8 +209,24 @@ pub fn component_to_tokens<const IS_SLOT: bool>(element: &Element) -> Option<Tok
     let mut attrs = TokenStream::new();
     let mut directive_paths: Vec<TokenStream> = Vec::new();
     // the variables (idents) to clone before making children
-    // in the form `let name = name.clone();`
-    let mut clones = TokenStream::new();
+    // in the form `let name = name.clone();`, collected first as they are
+    // also used by closure attributes.
+    let clone_list = element
+        .attrs()
+        .iter()
+        .filter_map(|a| match a {
+            Attr::Directive(dir) if dir.dir == "clone" => Some(component_clone_tokens(dir)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let clones = clone_list
+        .iter()
+        .map(|(_, clone)| clone.clone())
+        .collect::<TokenStream>();
+    // children closure arguments created by a `let:` directive, like `|data|`
+    let mut let_args: Option<TokenStream> = None;
+    // a `collect:children` directive, to pass the children as a `Vec`
+    let mut collect: Option<(&Directive, syn::Ident)> = None;
 
     // shorthands are not supported on slots
     if IS_SLOT {
@@ -217,6 +255,8 @@ pub fn component_to_tokens<const IS_SLOT: bool>(element: &Element) -> Option<Tok
                             key: KebabIdentOrStr::KebabIdent(class.clone()),
                             modifier: None,
                             value: None,
+                            optional: None,
+                            target_type: None,
                         })
                         .expect("class directive is known"),
                     );
@@ -237,6 +277,8 @@ pub fn component_to_tokens<const IS_SLOT: bool>(element: &Element) -> Option<Tok
                     key: parse_quote_spanned! { first_pound_symbol.span=> id },
                     modifier: None,
                     value: Some(Value::Lit(parse_quote!(#joined_ids))),
+                    optional: None,
+                    target_type: None,
                 })
                 .expect("attr directive is known"),
             );
@@ -244,7 +286,16 @@ pub fn component_to_tokens<const IS_SLOT: bool>(element: &Element) -> Option<Tok
     }
 
     element.attrs().iter().for_each(|a| match a {
-        Attr::Kv(attr) => attrs.extend(component_kv_attribute_tokens(attr)),
+        // handled by `slots_to_tokens`
+        Attr::Kv(attr) if IS_SLOT && attr.key().repr() == "when" => (),
+        Attr::Kv(attr) => attrs.extend(component_kv_attribute_tokens(attr, &clone_list)),
+        Attr::Struct(attr) => {
+            if IS_SLOT {
+                emit_error!(attr.span(), "struct attributes are not supported on slots");
+            } else {
+                attrs.extend(component_struct_attribute_tokens(attr, &path));
+            }
+        }
         Attr::Spread(spread) => {
             if IS_SLOT {
                 emit_error!(spread.span(), "spread syntax is not supported on slots");
@@ -253,10 +304,24 @@ pub fn component_to_tokens<const IS_SLOT: bool>(element: &Element) -> Option<Tok
             }
         }
         Attr::Directive(dir) => match dir.dir.to_string().as_str() {
-            // clone works on both components and slots
-            "clone" => {
-                emit_error_if_modifier(dir.modifier.as_ref());
-                clones.extend(component_clone_tokens(dir));
+            // clone works on both components and slots, already collected above
+            "clone" => (),
+            // let works on both components and slots
+            "let" => {
+                if let_args.is_some() {
+                    emit_error!(dir.dir.span(), "only one `let:` binding is allowed");
+                } else if element.children_args().is_some() {
+                    emit_error!(
+                        dir.dir.span(),
+                        "`let:` cannot be used with children closure arguments";
+                        help = "remove the `let:` directive and use the closure arguments instead"
+                    );
+                } else {
+                    let_args = Some(component_let_args(dir));
+                }
+            }
+            "collect" if !IS_SLOT => {
+                collect = Some((dir, component_collect_children(dir)));
             }
             // slots support no other directives
             other if IS_SLOT => {
@@ -274,19 +339,67 @@ pub fn component_to_tokens<const IS_SLOT: bool>(element: &Element) -> Option<Tok
 
     // convert the collected info into tokens //
 
-    let children = element.children().map(|children| {
-        let mut it = children.node_children().peekable();
-        // need to check that there are any element children at all,
-        // as components that accept slots may not accept children.
-        it.peek()
-            .is_some()
-            .then(|| component_children_tokens(it, element.children_args(), &clones))
-    });
+    // `children={f}` sets the children directly
+    let mut children_set_by_attr = false;
+    if let Some(attr) = element.attrs().iter().find_map(|a| match a {
+        Attr::Kv(kv) if kv.key().repr() == "children" => Some(kv),
+        _ => None,
+    }) {
+        if element
+            .children()
+            .is_some_and(|c| c.node_children().next().is_some())
+        {
+            emit_error!(
+                attr.key().span(),
+                "`children` cannot be set with both an attribute and a children block"
+            );
+            // leave out the block so that the builder does not error again
+            children_set_by_attr = true;
+        }
+    }
+
+    let args = element.children_args().or(let_args.as_ref());
+    let children = if let Some((collect, children_method)) = &collect {
+        if args.is_some() {
+            emit_error!(
+                collect.dir.span(),
+                "`collect:children` cannot be used with children closure arguments"
+            );
+        }
+        // the `Vec` is passed even if there are no children
+        let children = element
+            .children()
+            .into_iter()
+            .flat_map(Children::node_children);
+        Some(component_collected_children_tokens(
+            children,
+            children_method,
+            &clones,
+        ))
+    } else if children_set_by_attr {
+        None
+    } else {
+        element.children().and_then(|children| {
+            let mut it = children.node_children().peekable();
+            // need to check that there are any element children at all,
+            // as components that accept slots may not accept children.
+            it.peek()
+                .is_some()
+                .then(|| component_children_tokens(it, args, &clones))
+        })
+    };
 
     let slot_children = element
         .children()
         .map(|children| slots_to_tokens(children.slot_children()));
 
+    let view_props = element.children().map(|children| {
+        children
+            .prop_children()
+            .map(|(name, children)| component_view_prop_tokens(name, children, &clones))
+            .collect::<TokenStream>()
+    });
+
     // if attributes are missing, an error is made in `.build()` by the component
     // builder.
     let build = quote_spanned!(path.span()=> .build());
@@ -298,6 +411,7 @@ pub fn component_to_tokens<const IS_SLOT: bool>(element: &Element) -> Option<Tok
                 #path::builder()
                     #attrs
                     #children
+                    #view_props
                     #build
             )
         })
@@ -314,13 +428,16 @@ pub fn component_to_tokens<const IS_SLOT: bool>(element: &Element) -> Option<Tok
             }
         });
 
-        Some(quote! {
+        // also span the view call, so that any errors/warnings from a failed
+        // `.build()` (like unreachable code) point to the tag
+        Some(quote_spanned! { path.span()=>
             ::leptos::component::component_view(
                 &#path,
                 #component_props_builder
                     #attrs
                     #children
                     #slot_children
+                    #view_props
                     #build
             )
             #directive_paths
@@ -352,12 +469,16 @@ pub fn component_to_tokens<const IS_SLOT: bool>(element: &Element) -> Option<Tok
 ///     ])
 /// ```
 /// Where the slot's name is converted to snake_case for the method name.
+///
+/// Slots with a `when={cond}` attribute are only built if the condition is
+/// true, and are passed as an `Option` (or flattened into the vec if there
+/// are multiple slots with the same name).
 fn slots_to_tokens<'a>(children: impl Iterator<Item = &'a Element>) -> TokenStream {
     // collect to hashmap //
 
     // Mapping from the slot name (component, UpperCamelCase name, not snake_case)
     // to a vec of the each slot's expansion.
-    let mut slot_children = HashMap::<syn::Ident, Vec<TokenStream>>::new();
+    let mut slot_children = HashMap::<syn::Ident, Vec<(TokenStream, bool)>>::new();
     for el in children {
         let Tag::Component(path) = el.tag() else {
             panic!("called `slots_to_tokens` on non-slot element")
@@ -371,10 +492,20 @@ fn slots_to_tokens<'a>(children: impl Iterator<Item = &'a Element>) -> TokenStre
 
         let slot_component =
             component_to_tokens::<true>(el).expect("checked that element is a component");
-        slot_children
-            .entry(slot_name)
-            .or_default()
-            .push(slot_component);
+        let slot = match slot_condition(el) {
+            Some(cond) => {
+                let slot = quote! {
+                    if #cond {
+                        ::std::option::Option::Some(#slot_component)
+                    } else {
+                        ::std::option::Option::None
+                    }
+                };
+                (slot, true)
+            }
+            None => (slot_component, false),
+        };
+        slot_children.entry(slot_name).or_default().push(slot);
     }
 
     // convert to tokens //
@@ -388,10 +519,28 @@ fn slots_to_tokens<'a>(children: impl Iterator<Item = &'a Element>) -> TokenStre
 
             if slot_tokens.len() == 1 {
                 // don't wrap in a vec
+                let (slot, _) = &slot_tokens[0];
+                quote! {
+                    .#method(#slot)
+                }
+            } else if slot_tokens.iter().any(|(_, conditional)| *conditional) {
+                // wrap all slots in options and remove the excluded ones
+                let slot_tokens = slot_tokens.iter().map(|(slot, conditional)| {
+                    if *conditional {
+                        slot.clone()
+                    } else {
+                        quote! { ::std::option::Option::Some(#slot) }
+                    }
+                });
                 quote! {
-                    .#method(#(#slot_tokens)*)
+                    .#method(::std::iter::Iterator::collect::<::std::vec::Vec<_>>(
+                        ::std::iter::Iterator::flatten(::std::iter::IntoIterator::into_iter([
+                            #(#slot_tokens),*
+                        ]))
+                    ))
                 }
             } else {
+                let slot_tokens = slot_tokens.iter().map(|(slot, _)| slot);
                 quote! {
                     .#method(<[_]>::into_vec(::std::boxed::Box::new([
                         #(#slot_tokens),*
@@ -401,3 +550,17 @@ fn slots_to_tokens<'a>(children: impl Iterator<Item = &'a Element>) -> TokenStre
         })
         .collect()
 }
+
+/// Gets the condition of a slot from its `when={cond}` attribute.
+///
+/// The condition is only checked once, when the parent component is created:
+/// a bracketed value `when=[cond]` is not turned into a closure.
+fn slot_condition(el: &Element) -> Option<TokenStream> {
+    el.attrs().iter().find_map(|attr| match attr {
+        Attr::Kv(kv) if kv.key().repr() == "when" => Some(match kv.value() {
+            Value::Bracket { tokens, .. } => quote! { (#tokens) },
+            value => quote! { #value },
+        }),
+        _ => None,
+    })
+}
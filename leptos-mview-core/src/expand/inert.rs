@@ -0,0 +1,185 @@
+//! Rendering of fully static elements to a HTML string at compile time.
+//!
+//! Used for `InertElement`s with the `inert-html` feature, and by `mview_ssr!`.
+
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use syn::ext::IdentExt;
+
+use crate::ast::{attribute::selector::SelectorShorthand, Attr, Element, NodeChild, Tag, Value};
+
+/// Elements that never have a closing tag.
+///
+/// Keep this list sorted for binary search.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Converts an element into an `InertElement` if it (and all its children)
+/// have no reactive content.
+///
+/// Returns `None` if the element cannot be rendered at compile time. The
+/// rendered HTML is the same as what the regular expansion would render on
+/// the server.
+///
+/// ```text
+/// p.note { "Hello " strong("world") }
+/// => InertElement::new("<p class=\"note\">Hello <strong>world</strong></p>")
+/// ```
+///
+/// This should not be used for top-level elements, as `InertElement` does not
+/// support adding attributes.
+pub(super) fn inert_element_tokens(element: &Element) -> Option<TokenStream> {
+    let has_content = !element.attrs().is_empty()
+        || !element.selectors().is_empty()
+        || element
+            .children()
+            .is_some_and(|c| c.node_children().next().is_some());
+    // not worth it for a lone `div` or `br`
+    if !has_content {
+        return None;
+    }
+
+    let mut html = String::new();
+    push_element_html(element, &mut html, false)?;
+    let span = element.tag().span();
+    Some(quote_spanned! { span=> ::leptos::tachys::html::InertElement::new(#html) })
+}
+
+/// Renders a list of children to a HTML string if they are all static.
+///
+/// Unlike [`inert_element_tokens`], this includes the `<!>` markers that
+/// Leptos places between adjacent text nodes, so that the output is the same
+/// as rendering the view on the server.
+pub fn static_html<'a>(children: impl Iterator<Item = &'a NodeChild>) -> Option<String> {
+    let mut html = String::new();
+    push_children_html(children, &mut html, true)?;
+    Some(html)
+}
+
+/// Pushes the HTML of an element to the buffer, returning `None` if the
+/// element is not static.
+///
+/// If `text_markers` is true, a `<!>` is added between adjacent text nodes.
+fn push_element_html(element: &Element, html: &mut String, text_markers: bool) -> Option<()> {
+    // the source location attributes are only added in debug builds
+    if cfg!(feature = "source-locations") {
+        return None;
+    }
+    // svg/math need to be created in a different namespace, and the contents of
+    // scripts and styles should not be escaped.
+    let Tag::Html(ident) = element.tag() else {
+        return None;
+    };
+    let tag = ident.unraw().to_string();
+    if tag == "script" || tag == "style" || element.children_args().is_some() {
+        return None;
+    }
+
+    html.push('<');
+    html.push_str(&tag);
+
+    // attributes are rendered in the same order as the method expansion:
+    // key-value attributes, selector ids, then the merged class and style.
+    let mut classes = Vec::new();
+    let mut style = String::new();
+    for attr in element.attrs().iter() {
+        let Attr::Kv(kv) = attr else {
+            return None;
+        };
+        let key = kv.key().repr();
+        if let Some(value) = kv.keyword_bool_value() {
+            push_attr(html, key, &value.value());
+            continue;
+        }
+        match (key, kv.value()) {
+            ("class", Value::Lit(syn::Lit::Str(s))) => classes.push(s.value()),
+            ("style", Value::Lit(syn::Lit::Str(s))) => {
+                style.push_str(&s.value());
+                style.push(';');
+            }
+            ("ref" | "class" | "style", _) => return None,
+            (_, Value::Lit(syn::Lit::Str(s))) => push_attr(html, key, &s.value()),
+            (_, Value::Lit(syn::Lit::Bool(b))) if b.value => {
+                html.push(' ');
+                html.push_str(key);
+            }
+            _ => return None,
+        }
+    }
+
+    for sel in element.selectors().iter() {
+        match sel {
+            SelectorShorthand::Id { id, .. } => push_attr(html, "id", id.repr()),
+            SelectorShorthand::Class { class, .. } => classes.push(class.repr().to_string()),
+        }
+    }
+
+    if !classes.is_empty() {
+        push_attr(html, "class", &classes.join(" "));
+    }
+    if !style.is_empty() {
+        push_attr(html, "style", &style);
+    }
+    html.push('>');
+
+    if VOID_ELEMENTS.binary_search(&tag.as_str()).is_ok() {
+        return Some(());
+    }
+
+    if let Some(children) = element.children() {
+        push_children_html(children.node_children(), html, text_markers)?;
+    }
+
+    html.push_str("</");
+    html.push_str(&tag);
+    html.push('>');
+    Some(())
+}
+
+fn push_children_html<'a>(
+    children: impl Iterator<Item = &'a NodeChild>,
+    html: &mut String,
+    text_markers: bool,
+) -> Option<()> {
+    let mut after_text = false;
+    for child in children {
+        match child {
+            NodeChild::Value(Value::Lit(syn::Lit::Str(s))) => {
+                if text_markers && after_text {
+                    html.push_str("<!>");
+                }
+                push_escaped(html, &s.value(), false);
+                after_text = true;
+            }
+            NodeChild::Element(el) => {
+                push_element_html(el, html, text_markers)?;
+                after_text = false;
+            }
+            _ => return None,
+        }
+    }
+    Some(())
+}
+
+/// Pushes a ` key="value"` attribute, escaping the value.
+fn push_attr(html: &mut String, key: &str, value: &str) {
+    html.push(' ');
+    html.push_str(key);
+    html.push_str("=\"");
+    push_escaped(html, value, true);
+    html.push('"');
+}
+
+fn push_escaped(html: &mut String, s: &str, is_attr: bool) {
+    for c in s.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' if is_attr => html.push_str("&quot;"),
+            c => html.push(c),
+        }
+    }
+}
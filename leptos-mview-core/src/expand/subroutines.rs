@@ -1,6 +1,6 @@
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Span, TokenStream, TokenTree};
 use proc_macro_error2::emit_error;
-use quote::{quote, quote_spanned};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::{ext::IdentExt, spanned::Spanned};
 
 use crate::{
@@ -10,10 +10,14 @@ use crate::{
             kv::KvAttr,
             selector::{SelectorShorthand, SelectorShorthands},
             spread_attrs::SpreadAttr,
+            struct_attr::StructAttr,
         },
-        KebabIdentOrStr, NodeChild, TagKind, Value,
+        Attr, Children, Element, KebabIdentOrStr, NodeChild, Tag, TagKind, Value,
     },
-    expand::{children_fragment_tokens, emit_error_if_modifier, utils},
+    expand::{
+        children_fragment_tokens, emit_error_if_modifier, inert::inert_element_tokens, utils,
+    },
+    span,
 };
 
 ////////////////////////////////////////////////////////////////
@@ -25,25 +29,45 @@ use crate::{
 /// ```text
 /// use:d => (d, ().into())
 /// use:d={some_value} => (d, some_value.into())
+/// use:d=?{maybe_value} => (
+///     |el, value: Option<_>| if let Some(value) = value { d(el, value.into()) },
+///     maybe_value,
+/// )
 /// ```
 ///
 /// **Panics** if the provided directive is not `use:`.
-pub(super) fn use_directive_fn_value(u: &Directive) -> (syn::Ident, TokenStream) {
+pub(super) fn use_directive_fn_value(u: &Directive) -> (TokenStream, TokenStream) {
     let Directive {
         dir: use_token,
         key,
         modifier,
         value,
+        optional,
+        target_type: _,
     } = u;
     assert_eq!(use_token, "use", "directive should be `use:`");
     let directive_fn = key.to_ident_or_emit();
     emit_error_if_modifier(modifier.as_ref());
 
+    if let (Some(question), Some(val)) = (optional, value) {
+        let directive_fn = quote_spanned! {question.span=>
+            move |
+                el: ::leptos::tachys::renderer::types::Element,
+                value: ::std::option::Option<_>,
+            | {
+                if let ::std::option::Option::Some(value) = value {
+                    #directive_fn(el, ::std::convert::Into::into(value));
+                }
+            }
+        };
+        return (directive_fn, val.to_token_stream());
+    }
+
     let value = value.as_ref().map_or_else(
         || quote_spanned! {directive_fn.span()=> ().into() },
         |val| quote! { ::std::convert::Into::into(#val) },
     );
-    (directive_fn, value)
+    (directive_fn.into_token_stream(), value)
 }
 
 pub(super) fn event_listener_event_path(dir: &Directive) -> TokenStream {
@@ -52,6 +76,8 @@ pub(super) fn event_listener_event_path(dir: &Directive) -> TokenStream {
         key,
         modifier,
         value: _,
+        optional: _,
+        target_type: _,
     } = dir;
     assert_eq!(dir, "on", "directive should be `on:`");
 
@@ -61,24 +87,323 @@ pub(super) fn event_listener_event_path(dir: &Directive) -> TokenStream {
             emit_error!(s.span(), "event type must be an identifier");
             syn::Ident::new("invalid_event", s.span())
         }
+        KebabIdentOrStr::Dynamic(value) => {
+            emit_error!(value.span(), "event type must be an identifier");
+            syn::Ident::new("invalid_event", value.span())
+        }
+        KebabIdentOrStr::Field(_) => unreachable!("field paths are only parsed on `clone:`"),
     };
 
-    if let Some(modifier) = modifier {
-        if modifier == "undelegated" {
-            quote! {
-                ::leptos::tachys::html::event::#modifier(
-                    ::leptos::tachys::html::event::#ev_name
-                )
+    let event = quote! { ::leptos::tachys::html::event::#ev_name };
+    let Some(modifier) = modifier else {
+        return event;
+    };
+
+    match &*modifier.to_string() {
+        "capture" => quote! {
+            ::leptos::tachys::html::event::#modifier(#event)
+        },
+        // custom events are never delegated, so use one with the same name and
+        // event type.
+        "undelegated" => quote! {
+            ::leptos::tachys::html::event::Custom::<
+                <::leptos::tachys::html::event::#ev_name
+                    as ::leptos::tachys::html::event::EventDescriptor>::EventType
+            >::new(::leptos::tachys::html::event::EventDescriptor::name(&#event))
+        },
+        // also a custom event, with the listener options set to passive.
+        // the options can only be created in the browser.
+        "passive" => quote! {
+            {
+                let event = ::leptos::tachys::html::event::Custom::<
+                    <::leptos::tachys::html::event::#ev_name
+                        as ::leptos::tachys::html::event::EventDescriptor>::EventType
+                >::new(::leptos::tachys::html::event::EventDescriptor::name(&#event));
+                #[cfg(target_arch = "wasm32")]
+                let event = {
+                    let mut event = event;
+                    event.options_mut().set_passive(true);
+                    event
+                };
+                event
             }
-        } else {
+        },
+        // modifies the handler instead, see `event_listener_handler`.
+        "value" | "set" | "prevent_if" | "async" | "log" | "self" | "target" | "spa" => event,
+        other if key_modifier_name(other).is_some() => event,
+        _ => {
+            let known = EVENT_MODIFIERS
+                .iter()
+                .chain(KEY_MODIFIERS.iter().map(|(m, _)| m))
+                .map(|m| format!(":{m}"))
+                .collect::<Vec<_>>()
+                .join(", ");
             emit_error!(
                 modifier.span(), "unknown modifier";
-                help = ":undelegated is the only known modifier"
+                help = "known modifiers are {}", known
             );
-            quote! { ::leptos::tachys::html::event::#ev_name }
+            event
+        }
+    }
+}
+
+/// All modifiers that are supported on `on:` directives.
+const EVENT_MODIFIERS: &[&str] = &[
+    "undelegated",
+    "capture",
+    "passive",
+    "value",
+    "set",
+    "prevent_if",
+    "async",
+    "log",
+    "self",
+    "target",
+    "spa",
+];
+
+/// Modifiers that only run the handler for a key, and the `KeyboardEvent.key`
+/// that they match.
+const KEY_MODIFIERS: &[(&str, &str)] = &[
+    ("enter", "Enter"),
+    ("escape", "Escape"),
+    ("tab", "Tab"),
+    ("space", " "),
+    ("up", "ArrowUp"),
+    ("down", "ArrowDown"),
+    ("left", "ArrowLeft"),
+    ("right", "ArrowRight"),
+];
+
+/// Gets the `KeyboardEvent.key` of a key modifier like `enter`.
+fn key_modifier_name(modifier: &str) -> Option<&'static str> {
+    KEY_MODIFIERS
+        .iter()
+        .find(|(m, _)| *m == modifier)
+        .map(|(_, key)| *key)
+}
+
+/// Converts the value of an `on:` directive into the event handler.
+///
+/// Usually this is the value as-is, but some modifiers wrap the handler:
+/// ```text
+/// on:input:value={handler}
+/// => { let mut handler = handler; move |ev| handler(event_target_value(&ev)) }
+///
+/// on:input:set={setter}
+/// => { let setter = setter; move |ev| Set::set(&setter, event_target_value(&ev).into()) }
+///
+/// on:submit:prevent_if={handler}
+/// => { let mut handler = handler; move |ev| if handler(ev.clone()) { ev.prevent_default() } }
+///
+/// on:click:async={handler}
+/// => { let mut handler = handler; move |ev| spawn_local(handler(ev)) }
+///
+/// on:click:log={handler}
+/// => {
+///     let mut handler = handler;
+///     move |ev| {
+///         #[cfg(debug_assertions)]
+///         log!("on:click {:?}", ev);
+///         handler(ev)
+///     }
+/// }
+///
+/// on:click:self={handler}
+/// => {
+///     let mut handler = handler;
+///     move |ev| if ev.target() == ev.current_target() { handler(ev) }
+/// }
+///
+/// on:change:target::<HtmlInputElement>={handler}
+/// => { let mut handler = handler; move |ev| handler(event_target::<HtmlInputElement>(&ev), ev) }
+///
+/// on:click:spa={handler}
+/// => {
+///     let mut handler = handler;
+///     move |ev| {
+///         let anchor = ev.current_target().and_then(|t| t.dyn_into::<HtmlAnchorElement>().ok());
+///         if let Some(anchor) = anchor {
+///             ev.prevent_default();
+///             handler(anchor.href());
+///         }
+///     }
+/// }
+///
+/// on:keydown:enter={handler}
+/// => {
+///     let mut handler = handler;
+///     move |ev| if ev.key() == "Enter" { handler(ev) }
+/// }
+/// ```
+///
+/// **Panics** if the provided directive is not `on:`.
+pub(super) fn event_listener_handler(dir: &Directive) -> TokenStream {
+    let Directive {
+        dir,
+        key,
+        modifier,
+        value,
+        optional: _,
+        target_type,
+    } = dir;
+    assert_eq!(dir, "on", "directive should be `on:`");
+    let value = value.as_ref().map(Value::to_handler);
+
+    if let Some(modifier) = modifier {
+        if let Some(name) = key_modifier_name(&modifier.to_string()) {
+            return event_key_handler(modifier, name, value.as_ref());
+        }
+    }
+
+    match modifier {
+        Some(modifier) if modifier == "value" => {
+            let event_target_value =
+                quote_spanned! { modifier.span()=> ::leptos::tachys::dom::event_target_value };
+            quote! {
+                {
+                    let mut handler = #value;
+                    move |ev| handler(#event_target_value(&ev))
+                }
+            }
+        }
+        Some(modifier) if modifier == "set" => {
+            let event_target_value =
+                quote_spanned! { modifier.span()=> ::leptos::tachys::dom::event_target_value };
+            quote! {
+                {
+                    let setter = #value;
+                    move |ev| ::leptos::prelude::Set::set(
+                        &setter,
+                        ::std::convert::Into::into(#event_target_value(&ev)),
+                    )
+                }
+            }
+        }
+        Some(modifier) if modifier == "prevent_if" => {
+            let prevent_default = quote_spanned! { modifier.span()=> prevent_default };
+            quote! {
+                {
+                    let mut handler = #value;
+                    move |ev| {
+                        if handler(::std::clone::Clone::clone(&ev)) {
+                            ev.#prevent_default();
+                        }
+                    }
+                }
+            }
+        }
+        Some(modifier) if modifier == "async" => {
+            let spawn_local = quote_spanned! { modifier.span()=> ::leptos::task::spawn_local };
+            quote! {
+                {
+                    let mut handler = #value;
+                    move |ev| #spawn_local(handler(ev))
+                }
+            }
+        }
+        Some(modifier) if modifier == "log" => {
+            // errors for other keys are already emitted by `event_listener_event_path`
+            let name = match key {
+                KebabIdentOrStr::KebabIdent(ident) => format!("on:{}", ident.repr()),
+                _ => String::from("on:"),
+            };
+            let log = quote_spanned! { modifier.span()=> ::leptos::logging::log! };
+            quote! {
+                {
+                    let mut handler = #value;
+                    move |ev| {
+                        #[cfg(debug_assertions)]
+                        #log("{} {:?}", #name, ev);
+                        handler(ev)
+                    }
+                }
+            }
+        }
+        Some(modifier) if modifier == "target" => {
+            event_target_handler(modifier, value.as_ref(), target_type.as_deref())
+        }
+        Some(modifier) if modifier == "spa" => event_spa_handler(modifier, value.as_ref()),
+        Some(modifier) if modifier == "self" => {
+            // only run if the event was dispatched on this element, not a child
+            let target = quote_spanned! { modifier.span()=> target };
+            quote! {
+                {
+                    let mut handler = #value;
+                    move |ev| {
+                        if ev.#target() == ev.current_target() {
+                            handler(ev);
+                        }
+                    }
+                }
+            }
+        }
+        _ => quote! { #value },
+    }
+}
+
+/// Wraps the handler of an `on:event:target::<Type>` directive, which takes
+/// the target element cast to `Type` as well as the event.
+fn event_target_handler(
+    modifier: &syn::Ident,
+    value: Option<&Value>,
+    target_type: Option<&syn::Type>,
+) -> TokenStream {
+    let event_target = quote_spanned! { modifier.span()=> ::leptos::tachys::dom::event_target };
+    // the closure needs to know the element type before it is called
+    quote! {
+        {
+            fn __mview_target_handler<E, R>(
+                f: impl ::std::ops::FnMut(#target_type, E) -> R,
+            ) -> impl ::std::ops::FnMut(#target_type, E) -> R {
+                f
+            }
+            let mut handler = __mview_target_handler(#value);
+            move |ev| handler(#event_target::<#target_type>(&ev), ev)
+        }
+    }
+}
+
+/// Wraps the handler of an `on:click:spa` directive, which prevents the
+/// navigation of an anchor and passes its resolved `href` to the handler
+/// instead.
+///
+/// Nothing happens if the listener is not on an `a` element.
+fn event_spa_handler(modifier: &syn::Ident, value: Option<&Value>) -> TokenStream {
+    // spanned so that an error from a non-DOM event points to the modifier
+    let current_target = quote_spanned! { modifier.span()=> current_target };
+    quote! {
+        {
+            let mut handler = #value;
+            move |ev| {
+                let anchor = ev.#current_target().and_then(|target| {
+                    ::leptos::wasm_bindgen::JsCast::dyn_into::<
+                        ::leptos::web_sys::HtmlAnchorElement
+                    >(target).ok()
+                });
+                if let ::std::option::Option::Some(anchor) = anchor {
+                    ev.prevent_default();
+                    handler(anchor.href());
+                }
+            }
+        }
+    }
+}
+
+/// Wraps the handler of a key modifier like `on:keydown:enter`, which only
+/// runs the handler if the `key` of the event is `name`.
+fn event_key_handler(modifier: &syn::Ident, name: &str, value: Option<&Value>) -> TokenStream {
+    // spanned so that an error from a non-keyboard event points to the modifier
+    let key = quote_spanned! { modifier.span()=> key };
+    quote! {
+        {
+            let mut handler = #value;
+            move |ev| {
+                if ev.#key() == #name {
+                    handler(ev);
+                }
+            }
         }
-    } else {
-        quote! { ::leptos::tachys::html::event::#ev_name }
     }
 }
 
@@ -122,10 +447,14 @@ impl From<&str> for AttributeKind {
 
 /// Converts element class/id selector shorthands into a series of `.classes`
 /// and `.id` calls.
-pub(super) fn xml_selectors_tokens(selectors: &SelectorShorthands) -> TokenStream {
+pub(super) fn xml_selectors_tokens(
+    selectors: &SelectorShorthands,
+    skip_classes: bool,
+) -> TokenStream {
     let (classes, ids): (Vec<_>, Vec<_>) = selectors
         .iter()
         .partition(|sel| matches!(sel, SelectorShorthand::Class { .. }));
+    let classes = if skip_classes { &[][..] } else { &classes[..] };
 
     let class_methods = classes.iter().map(|class| {
         let method = syn::Ident::new("class", class.prefix().span());
@@ -142,13 +471,97 @@ pub(super) fn xml_selectors_tokens(selectors: &SelectorShorthands) -> TokenStrea
     quote! { #(#class_methods)* #(#id_methods)* }
 }
 
-pub(super) fn xml_kv_attribute_tokens(attr: &KvAttr, element_tag: TagKind) -> TokenStream {
+/// Merges all classes on an element that are known at compile time (string
+/// `class="..."` attributes and `.class` selectors) into a single
+/// `.class("...")` call.
+///
+/// ```text
+/// div.a.b class="c d" => div().class("c d a b")
+/// ```
+///
+/// Returns `None` if there are less than two static classes to merge, or if
+/// there is also a dynamic `class={...}` attribute (a string class replaces
+/// all other classes, so it can only be used before the dynamic ones).
+pub(super) fn xml_static_class_tokens(element: &Element) -> Option<TokenStream> {
+    let mut attr_classes = Vec::new();
+    for attr in element.attrs().iter() {
+        let Attr::Kv(kv) = attr else { continue };
+        if let Some(lit) = static_class_lit(kv) {
+            attr_classes.push(lit);
+        } else if kv.key().repr() == "class" {
+            return None;
+        }
+    }
+    let selector_classes = element
+        .selectors()
+        .iter()
+        .filter(|sel| matches!(sel, SelectorShorthand::Class { .. }))
+        .collect::<Vec<_>>();
+    if attr_classes.len() + selector_classes.len() < 2 {
+        return None;
+    }
+
+    let span = attr_classes
+        .first()
+        .copied()
+        .map_or_else(|| selector_classes[0].prefix().span(), syn::LitStr::span);
+    let class = attr_classes
+        .iter()
+        .copied()
+        .map(syn::LitStr::value)
+        .chain(
+            selector_classes
+                .iter()
+                .map(|sel| sel.ident().repr().to_owned()),
+        )
+        .collect::<Vec<_>>()
+        .join(" ");
+    // keep the selectors colored like the individual `.class` calls
+    let dummy_items = span::color_all(selector_classes.iter().flat_map(|sel| sel.ident().spans()));
+    let method = syn::Ident::new("class", span);
+    Some(quote! { .#method({#(#dummy_items)* #class}) })
+}
+
+/// Returns the string literal of a `class="..."` attribute.
+pub(super) fn static_class_lit(attr: &KvAttr) -> Option<&syn::LitStr> {
+    match attr.value() {
+        Value::Lit(syn::Lit::Str(lit)) if attr.key().repr() == "class" => Some(lit),
+        _ => None,
+    }
+}
+
+/// Adds a `data-mview-loc="file:line"` attribute with the location of the
+/// tag, to find where an element came from in the browser's dev tools.
+///
+/// The attribute is only added in debug builds, as the macro does not know
+/// which profile the code is being compiled with.
+#[cfg(feature = "source-locations")]
+pub(super) fn xml_source_location_tokens(tag: &Tag) -> TokenStream {
+    // `file!` and `line!` take the location from their span
+    quote_spanned! { tag.span()=>
+        .attr(
+            "data-mview-loc",
+            ::std::cfg!(debug_assertions)
+                .then_some(::std::concat!(::std::file!(), ":", ::std::line!())),
+        )
+    }
+}
+
+pub(super) fn xml_kv_attribute_tokens(attr: &KvAttr, element_tag: &Tag) -> TokenStream {
     let key = attr.key();
-    let value = attr.value();
+    let keyword_value = attr.keyword_bool_value().map(|lit| Value::Lit(lit.into()));
+    let value = keyword_value.as_ref().unwrap_or_else(|| attr.value());
+    #[cfg(feature = "lints")]
+    value.warn_if_bracketed_lit();
     // special cases
     if key.repr() == "ref" {
         let node_ref = syn::Ident::new("node_ref", key.span());
         quote! { .#node_ref(#value) }
+    } else if key.repr() == "value" && attr.is_shorthand() && is_form_control(element_tag) {
+        // `{value}` on a form control is usually a signal for a controlled input,
+        // which needs to set the property instead of the initial value attribute.
+        let prop = syn::Ident::new("prop", key.span());
+        quote! { .#prop("value", #value) }
     } else {
         // https://github.com/leptos-rs/leptos/blob/main/leptos_macro/src/view/mod.rs#L960
         // Use unchecked attributes if:
@@ -156,7 +569,7 @@ pub(super) fn xml_kv_attribute_tokens(attr: &KvAttr, element_tag: TagKind) -> To
         // - It's a custom web component or SVG element
         // - or it's a custom or data attribute (has `-` except for `aria-`)
         let attr_kind = AttributeKind::from(key.repr());
-        let is_web_or_svg = matches!(element_tag, TagKind::Svg | TagKind::WebComponent);
+        let is_web_or_svg = matches!(element_tag.kind(), TagKind::Svg | TagKind::WebComponent);
 
         if (is_web_or_svg || attr_kind.is_custom()) && !attr_kind.is_class_or_style() {
             // unchecked attribute
@@ -172,18 +585,94 @@ pub(super) fn xml_kv_attribute_tokens(attr: &KvAttr, element_tag: TagKind) -> To
     }
 }
 
-pub(super) fn xml_directive_tokens(directive: &Directive) -> TokenStream {
+/// Converts a `class:{..names}=[cond]` directive to an attribute that toggles
+/// every class in `names` with the same condition.
+///
+/// The names can be any iterator of `&'static str`, like a `const` array. The
+/// macro cannot read the names, so a `class` is made for each name at runtime
+/// and they are combined into a `Vec<AnyAttribute>`:
+/// ```ignore
+/// {
+///     let cond = move || cond;
+///     names.into_iter().map(|name| class((name, cond.clone())).into_any_attr()).collect::<Vec<_>>()
+/// }
+/// ```
+///
+/// Returns `None` if the directive does not have a `..` name.
+fn spread_class_attr(directive: &Directive) -> Option<TokenStream> {
+    let KebabIdentOrStr::Dynamic(Value::Block { tokens, .. }) = &directive.key else {
+        return None;
+    };
+    let mut tokens = tokens.clone().into_iter();
+    let (Some(TokenTree::Punct(p1)), Some(TokenTree::Punct(p2))) = (tokens.next(), tokens.next())
+    else {
+        return None;
+    };
+    if p1.as_char() != '.' || p2.as_char() != '.' {
+        return None;
+    }
+    if directive.dir != "class" {
+        emit_error!(
+            p1.span(),
+            "multiple names are only supported on `class:` directives"
+        );
+        return None;
+    }
+    let names = tokens.collect::<TokenStream>();
+    let value = directive.value.clone().unwrap_or_else(Value::new_true);
+    let any_attribute = quote! { ::leptos::tachys::html::attribute::any_attribute };
+
+    Some(quote! {
+        {
+            let __mview_cond = #value;
+            ::std::iter::Iterator::collect::<::std::vec::Vec<#any_attribute::AnyAttribute>>(
+                ::std::iter::Iterator::map(
+                    ::std::iter::IntoIterator::into_iter(#names),
+                    move |name: &'static str| #any_attribute::IntoAnyAttribute::into_any_attr(
+                        ::leptos::tachys::html::class::class((
+                            name,
+                            ::std::clone::Clone::clone(&__mview_cond),
+                        ))
+                    ),
+                ),
+            )
+        }
+    })
+}
+
+/// Whether the tag is a HTML `input`, `textarea` or `select` element.
+fn is_form_control(tag: &Tag) -> bool {
+    let Tag::Html(ident) = tag else {
+        return false;
+    };
+    matches!(
+        ident.unraw().to_string().as_str(),
+        "input" | "textarea" | "select"
+    )
+}
+
+pub(super) fn xml_directive_tokens(directive: &Directive, element_tag: &Tag) -> TokenStream {
     let Directive {
         dir,
         key,
         modifier,
         value,
+        optional: _,
+        target_type: _,
     } = directive;
 
     match dir.to_string().as_str() {
         "class" | "style" => {
-            let key = key.to_lit_str();
             emit_error_if_modifier(modifier.as_ref());
+            if let Some(attr) = spread_class_attr(directive) {
+                let add_any_attr = syn::Ident::new("add_any_attr", dir.span());
+                return quote! { .#add_any_attr(#attr) };
+            }
+            // names can be computed with a block, `class:{concat!(...)}={value}`
+            let key = match key {
+                KebabIdentOrStr::Dynamic(name) => name.to_token_stream(),
+                key => key.to_lit_str().to_token_stream(),
+            };
             quote! { .#dir((#key, #value)) }
         }
         "prop" => {
@@ -193,7 +682,8 @@ pub(super) fn xml_directive_tokens(directive: &Directive) -> TokenStream {
         }
         "on" => {
             let event_path = event_listener_event_path(directive);
-            quote! { .#dir(#event_path, #value) }
+            let handler = event_listener_handler(directive);
+            quote! { .#dir(#event_path, #handler) }
         }
         "use" => {
             let (fn_name, value) = use_directive_fn_value(directive);
@@ -202,18 +692,34 @@ pub(super) fn xml_directive_tokens(directive: &Directive) -> TokenStream {
                 .#directive(#fn_name, #value)
             }
         }
-        "attr" | "clone" => {
+        // static attribute names should just be normal attributes on elements,
+        // but string names are kept to allow any name (like `attr:"on:x"`)
+        "attr" if matches!(key, KebabIdentOrStr::Dynamic(_) | KebabIdentOrStr::Str(_)) => {
+            xml_unchecked_attr_tokens(directive)
+        }
+        "attr" | "clone" | "let" | "collect" => {
             emit_error!(dir.span(), "`{}:` is not supported on elements", dir);
             quote! {}
         }
         "bind" => {
             emit_error_if_modifier(modifier.as_ref());
+            // custom elements may have their own `value` property, so only
+            // check known elements.
+            if !is_form_control(element_tag) && !matches!(element_tag, Tag::WebComponent(_)) {
+                emit_error!(
+                    dir.span(),
+                    "`bind:` is only supported on `input`, `select` and `textarea` elements";
+                    help = "use `prop:` and `on:` to bind to other elements"
+                );
+            }
             let bind = syn::Ident::new("bind", dir.span());
             let bound_attribute_name = utils::snake_case_to_upper_camel(key.to_ident_or_emit());
 
             // https://github.com/leptos-rs/leptos/pull/3680/files
             // special case for `bind:group`
-            if key.to_lit_str().value() == "group" {
+            if key.to_lit_str().value() == "number" {
+                xml_bind_number_tokens(directive)
+            } else if key.to_lit_str().value() == "group" {
                 quote! { .#bind(::leptos::tachys::reactive_graph::bind::#bound_attribute_name, #value) }
             } else {
                 quote! { .#bind(::leptos::attr::#bound_attribute_name, #value) }
@@ -226,6 +732,84 @@ pub(super) fn xml_directive_tokens(directive: &Directive) -> TokenStream {
     }
 }
 
+/// Converts an `attr:{name}={value}` directive with a runtime attribute name,
+/// or an `attr:"name"={value}` directive with a string name, into an
+/// unchecked attribute.
+///
+/// ```ignore
+/// .attr(name, IntoAttributeValue::into_attribute_value(value))
+/// ```
+///
+/// String names are used as-is, so they can be names that would otherwise be
+/// read as a directive, like `attr:"on:custom"`.
+fn xml_unchecked_attr_tokens(directive: &Directive) -> TokenStream {
+    let Directive {
+        dir,
+        key,
+        modifier,
+        value,
+        optional: _,
+        target_type: _,
+    } = directive;
+    let name = match key {
+        KebabIdentOrStr::Dynamic(name) => name.to_token_stream(),
+        KebabIdentOrStr::Str(name) => name.to_token_stream(),
+        _ => panic!("directive should have a dynamic or string name"),
+    };
+    emit_error_if_modifier(modifier.as_ref());
+    let value = value.clone().unwrap_or_else(Value::new_true);
+    quote! {
+        .#dir(#name, ::leptos::prelude::IntoAttributeValue::into_attribute_value(#value))
+    }
+}
+
+/// Converts a `bind:number={signal}` directive into a `value` property and
+/// `input` event listener that parses the input.
+///
+/// The signal can be anything that `bind:value` accepts, with a value that
+/// implements `FromStr` and `Display`. If the input fails to parse, the signal
+/// is left unchanged.
+///
+/// ```ignore
+/// .add_any_attr({
+///     let (read, write) = IntoSplitSignal::into_split_signal(signal);
+///     (
+///         prop("value", move || read.get().to_string()),
+///         on(input, move |ev| {
+///             if let Ok(parsed) = event_target_value(&ev).parse() {
+///                 write.set(parsed);
+///             }
+///         }),
+///     )
+/// })
+/// ```
+fn xml_bind_number_tokens(directive: &Directive) -> TokenStream {
+    let Directive { dir, value, .. } = directive;
+    let add_any_attr = syn::Ident::new("add_any_attr", dir.span());
+    quote! {
+        .#add_any_attr({
+            let (read, write) =
+                ::leptos::tachys::reactive_graph::bind::IntoSplitSignal::into_split_signal(#value);
+            (
+                ::leptos::tachys::html::property::prop(
+                    "value",
+                    move || ::std::string::ToString::to_string(&::leptos::prelude::Get::get(&read)),
+                ),
+                ::leptos::tachys::html::event::on(
+                    ::leptos::tachys::html::event::input,
+                    move |ev| {
+                        if let ::std::result::Result::Ok(parsed) =
+                            ::std::primitive::str::parse(&::leptos::tachys::dom::event_target_value(&ev))
+                        {
+                            ::leptos::prelude::Set::set(&write, parsed);
+                        }
+                    },
+                ),
+            )
+        })
+    }
+}
+
 pub(super) fn xml_spread_tokens(attr: &SpreadAttr) -> TokenStream {
     let (dotdot, expr) = (attr.dotdot(), attr.expr());
     let attrs = syn::Ident::new("add_any_attr", dotdot.span());
@@ -250,9 +834,17 @@ pub(super) fn xml_child_methods_tokens<'a>(
     let mut ts = TokenStream::new();
     for child in children {
         let child_method = syn::Ident::new("child", child.span());
-        ts.extend(quote! {
-            .#child_method(#child)
-        });
+        let inert = match child {
+            NodeChild::Element(el) if cfg!(feature = "inert-html") => inert_element_tokens(el),
+            _ => None,
+        };
+        if let Some(inert) = inert {
+            ts.extend(quote! { .#child_method(#inert) });
+        } else {
+            ts.extend(quote! {
+                .#child_method(#child)
+            });
+        }
     }
     ts
 }
@@ -261,20 +853,175 @@ pub(super) fn xml_child_methods_tokens<'a>(
 // ------------------- component only ------------------- //
 ////////////////////////////////////////////////////////////
 
-pub(super) fn component_kv_attribute_tokens(attr: &KvAttr) -> TokenStream {
+/// Converts a `key=value` attribute to a builder method call.
+///
+/// Bracket values are closures, like `Show when=[flag.get()]`, so any clones
+/// (from `clone:`) that are used in the closure are added before it:
+/// ```ignore
+/// .when({
+///     let flag = flag.clone();
+///     move || flag.get()
+/// })
+/// ```
+///
+/// An existing closure can be passed as the children with `children={f}`,
+/// which is converted with `ToChildren` like normal children.
+pub(super) fn component_kv_attribute_tokens(
+    attr: &KvAttr,
+    clones: &[(syn::Ident, TokenStream)],
+) -> TokenStream {
     let (key, value) = (attr.key().to_snake_ident(), attr.value());
+    if attr.key().repr() == "children" {
+        let to_children =
+            quote_spanned! { value.span()=> ::leptos::children::ToChildren::to_children };
+        return quote_spanned! { attr.span()=> .#key(#to_children(#value)) };
+    }
+    if let Value::Bracket { tokens, .. } = value {
+        // only clone what is used, so that unused clones don't warn
+        let used_clones = clones
+            .iter()
+            .filter(|(name, _)| tokens_contain_ident(tokens, name))
+            .map(|(_, clone)| clone)
+            .collect::<Vec<_>>();
+        if !used_clones.is_empty() {
+            return quote_spanned! { attr.span()=> .#key({ #(#used_clones)* #value }) };
+        }
+    }
     quote_spanned! { attr.span()=> .#key(#value) }
 }
 
+/// Whether the tokens (including any nested groups) contain the ident.
+pub fn tokens_contain_ident(tokens: &TokenStream, ident: &syn::Ident) -> bool {
+    tokens.clone().into_iter().any(|tt| match tt {
+        TokenTree::Ident(i) => i.unraw() == ident.unraw(),
+        TokenTree::Group(group) => tokens_contain_ident(&group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Builds a struct prop from its fields, starting from `Default::default()`.
+///
+/// The type of the struct is taken from the component's props, as it is not
+/// known by the macro:
+/// ```ignore
+/// Chart config:{ width=300 animated };
+/// // expands to
+/// .config({
+///     fn __mview_build_struct<P, T: Default>(_: &impl Component<P>, _: fn(&P) -> &T, f: impl FnOnce(&mut T)) -> T { ... }
+///     __mview_build_struct(&Chart, |props| &props.config, |__mview_struct| {
+///         __mview_struct.width = 300;
+///         __mview_struct.animated = true;
+///     })
+/// })
+/// ```
+pub(super) fn component_struct_attribute_tokens(
+    attr: &StructAttr,
+    path: &syn::Path,
+) -> TokenStream {
+    let key = attr.key().to_snake_ident();
+    let fields = attr.fields().iter().map(|field| {
+        let (name, value) = (field.key().to_snake_ident(), field.value());
+        quote_spanned! { field.span()=> __mview_struct.#name = #value; }
+    });
+    quote_spanned! { attr.span()=>
+        .#key({
+            fn __mview_build_struct<P, T: ::std::default::Default>(
+                _: &impl ::leptos::component::Component<P>,
+                _: fn(&P) -> &T,
+                f: impl ::std::ops::FnOnce(&mut T),
+            ) -> T {
+                let mut value = <T as ::std::default::Default>::default();
+                f(&mut value);
+                value
+            }
+            __mview_build_struct(&#path, |props| &props.#key, |__mview_struct| {
+                #(#fields)*
+            })
+        })
+    }
+}
+
 /// Expands to a `let` statement `let to_clone = to_clone.clone();`.
-pub(super) fn component_clone_tokens(dir: &Directive) -> TokenStream {
-    let to_clone = dir.key.to_ident_or_emit();
+///
+/// Also returns the name of the cloned variable.
+pub(super) fn component_clone_tokens(dir: &Directive) -> (syn::Ident, TokenStream) {
     emit_error_if_modifier(dir.modifier.as_ref());
     if let Some(value) = &dir.value {
         emit_error!(value.span(), "`clone:` does not take any values");
     };
 
-    quote! { let #to_clone = #to_clone.clone(); }
+    // `clone:self.data` => `let data = self.data.clone();`
+    if let KebabIdentOrStr::Field(field) = &dir.key {
+        let syn::Member::Named(name) = &field.member else {
+            unreachable!("field paths are only parsed with named fields")
+        };
+        return (name.clone(), quote! { let #name = #field.clone(); });
+    }
+
+    let to_clone = dir.key.to_ident_or_emit();
+    let tokens = quote! { let #to_clone = #to_clone.clone(); };
+    (to_clone, tokens)
+}
+
+/// Converts a `let:binding` directive to closure arguments `|binding|` for the
+/// children.
+///
+/// This is equivalent to writing the closure arguments directly before the
+/// children block.
+pub(super) fn component_let_args(dir: &Directive) -> TokenStream {
+    let binding = dir.key.to_ident_or_emit();
+    emit_error_if_modifier(dir.modifier.as_ref());
+    if let Some(value) = &dir.value {
+        emit_error!(value.span(), "`let:` does not take any values");
+    }
+
+    quote_spanned! { dir.dir.span()=> |#binding| }
+}
+
+/// Checks a `collect:children` directive, which makes the children be passed
+/// as a `Vec<AnyView>` instead of a single `Children`.
+///
+/// Returns the `children` method to call on the builder.
+pub(super) fn component_collect_children(dir: &Directive) -> syn::Ident {
+    let key = dir.key.to_ident_or_emit();
+    if key.unraw() != "children" {
+        emit_error!(
+            key.span(), "only `collect:children` is supported";
+            help = "`collect:` collects the children into a `Vec<AnyView>`"
+        );
+    }
+    emit_error_if_modifier(dir.modifier.as_ref());
+    if let Some(value) = &dir.value {
+        emit_error!(value.span(), "`collect:` does not take any values");
+    }
+    syn::Ident::new("children", key.span())
+}
+
+/// Converts children to a `Vec` of views, for components with a
+/// `collect:children` directive.
+///
+/// ```ignore
+/// .children({
+///     // any clones
+///     let clone = clone.clone();
+///     vec![child1.into_any(), child2.into_any()]
+/// })
+/// ```
+pub(super) fn component_collected_children_tokens<'a>(
+    children: impl Iterator<Item = &'a NodeChild>,
+    children_method: &syn::Ident,
+    clones: &TokenStream,
+) -> TokenStream {
+    let children = children.map(|child| {
+        quote_spanned! { child.span()=> ::leptos::prelude::IntoAny::into_any(#child) }
+    });
+
+    quote! {
+        .#children_method({
+            #clones
+            ::std::vec![#(#children),*]
+        })
+    }
 }
 
 /// Converts children to tokens for use by components.
@@ -372,7 +1119,7 @@ pub(super) fn component_children_tokens<'a>(
 ///         leptos::tachys::html::attribute::custom::custom_attribute("data-index", 0),
 ///         leptos::tachys::html::property::prop("value", "aaaa"),
 ///         leptos::tachys::html::event::on(
-///             leptos::tachys::html::event::undelegated(
+///             leptos::tachys::html::event::capture(
 ///                 leptos::tachys::html::event::click
 ///             ),
 ///             || ()
@@ -385,17 +1132,33 @@ pub(super) fn directive_to_any_attr_path(directive: &Directive) -> Option<TokenS
     let dir = &directive.dir;
     let path = match &*dir.to_string() {
         "class" | "style" => {
-            // avoid making it string coloured
-            let key = directive.key.to_unspanned_string();
-            let value = directive.value.clone().unwrap_or_else(Value::new_true);
             // to avoid spanning the directive to the module
             let dir_unspanned = syn::Ident::new(&dir.to_string(), Span::call_site());
+            if let Some(attr) = spread_class_attr(directive) {
+                return Some(attr);
+            }
+            let key = match &directive.key {
+                KebabIdentOrStr::Dynamic(name) => name.to_token_stream(),
+                // avoid making it string coloured
+                key => key.to_unspanned_string().to_token_stream(),
+            };
+            let value = directive.value.clone().unwrap_or_else(Value::new_true);
             quote! {
                 ::leptos::tachys::html::#dir_unspanned::#dir((#key, #value))
             }
         }
         "attr" => {
-            let attr_kind = AttributeKind::from(&*directive.key.to_lit_str().value());
+            if let KebabIdentOrStr::Dynamic(name) = &directive.key {
+                let value = directive.value.clone().unwrap_or_else(Value::new_true);
+                return Some(quote! {
+                    ::leptos::tachys::html::attribute::custom::custom_attribute(#name, #value)
+                });
+            }
+            let mut attr_kind = AttributeKind::from(&*directive.key.to_lit_str().value());
+            // string names are used as-is, like `attr:"on:custom"`
+            if matches!(directive.key, KebabIdentOrStr::Str(_)) && !attr_kind.is_class_or_style() {
+                attr_kind = AttributeKind::Custom;
+            }
             match attr_kind {
                 AttributeKind::Class | AttributeKind::Style => {
                     let class_or_style = directive.key.to_ident_or_emit();
@@ -432,9 +1195,9 @@ pub(super) fn directive_to_any_attr_path(directive: &Directive) -> Option<TokenS
         }
         "on" => {
             let event_path = event_listener_event_path(directive);
-            let value = &directive.value;
+            let handler = event_listener_handler(directive);
             quote! {
-                ::leptos::tachys::html::event::on(#event_path, #value)
+                ::leptos::tachys::html::event::on(#event_path, #handler)
             }
         }
         "use" => {
@@ -457,3 +1220,33 @@ pub(super) fn directive_to_any_attr_path(directive: &Directive) -> Option<TokenS
 ///
 /// Spread attrs are added as `.add_any_attr(expr)`.
 pub(super) fn component_spread_tokens(attr: &SpreadAttr) -> TokenStream { attr.expr().clone() }
+
+/// Converts a view prop `name: (...)` of a component to a `ViewFn`.
+///
+/// ```ignore
+/// .name({
+///     // any clones
+///     let clone = clone.clone();
+///     ViewFn::from(move || children)
+/// })
+/// ```
+pub(super) fn component_view_prop_tokens(
+    name: &syn::Ident,
+    children: &Children,
+    clones: &TokenStream,
+) -> TokenStream {
+    for slot in children.slot_children() {
+        emit_error!(
+            slot.tag().span(),
+            "slots are not supported inside view props"
+        );
+    }
+    let fragment = children_fragment_tokens(children.node_children(), Span::call_site());
+    let view_fn = quote_spanned! { name.span()=> ::leptos::prelude::ViewFn::from };
+    quote! {
+        .#name({
+            #clones
+            #view_fn(move || #fragment)
+        })
+    }
+}
@@ -5,3 +5,19 @@ syn::custom_keyword!(on);
 syn::custom_keyword!(prop);
 syn::custom_keyword!(clone);
 syn::custom_keyword!(slot);
+syn::custom_keyword!(err_boundary);
+syn::custom_keyword!(children);
+syn::custom_keyword!(any);
+syn::custom_keyword!(raw_text);
+syn::custom_keyword!(template);
+syn::custom_keyword!(provide);
+syn::custom_keyword!(resource);
+syn::custom_keyword!(each);
+syn::custom_keyword!(lazy);
+syn::custom_keyword!(space);
+syn::custom_keyword!(nbsp);
+syn::custom_keyword!(key);
+syn::custom_keyword!(with);
+syn::custom_keyword!(live_region);
+syn::custom_keyword!(show_hidden);
+syn::custom_keyword!(auto);
@@ -12,12 +12,19 @@ mod kw;
 mod parse;
 mod span;
 
-use ast::{Child, Children};
-use expand::root_children_tokens;
-use proc_macro2::{Span, TokenStream};
-use proc_macro_error2::abort;
-use quote::quote;
-use syn::spanned::Spanned;
+use ast::{
+    emit_undefined_id_refs, new_auto_id_tokens, parse_if_tokens, reset_auto_ids, reset_str_consts,
+    str_const_tokens, Child, Children, GlobalListener, NodeChild, Provide, Template, Value,
+};
+use expand::{root_children_tokens, static_html};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use proc_macro_error2::{abort, emit_error};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse::{discouraged::Speculative, Parse, ParseStream, Parser},
+    spanned::Spanned,
+    Token,
+};
 
 #[must_use]
 pub fn mview_impl(input: TokenStream) -> TokenStream {
@@ -25,11 +32,166 @@ pub fn mview_impl(input: TokenStream) -> TokenStream {
     // invocation" e.g. when assigning `let res = mview! { ... };`
     proc_macro_error2::set_dummy(quote! { () });
 
-    let children = match syn::parse2::<Children>(input) {
+    let MacroInput {
+        any,
+        provides,
+        listeners,
+        templates,
+        children,
+    } = match parse_input.parse2(input) {
         Ok(tree) => tree,
         Err(e) => return e.to_compile_error(),
     };
 
+    let view = with_setup(&provides, &listeners, templates, view_tokens(children));
+    if let Some(any) = any {
+        quote_spanned! { any.span=> ::leptos::prelude::IntoAny::into_any(#view) }
+    } else {
+        view
+    }
+}
+
+/// Renders the view to a `String` with `to_html`, folding fully static views
+/// at compile time.
+///
+/// If every element is static, the HTML is rendered at compile time to a
+/// string literal. Otherwise, this expands to the same view as [`mview_impl`]
+/// and renders it with `to_html` at runtime.
+#[must_use]
+pub fn mview_ssr_impl(input: TokenStream) -> TokenStream {
+    proc_macro_error2::set_dummy(quote! { ::std::string::String::new() });
+
+    let MacroInput {
+        any,
+        provides,
+        listeners,
+        templates,
+        children,
+    } = match parse_input.parse2(input) {
+        Ok(tree) => tree,
+        Err(e) => return e.to_compile_error(),
+    };
+    if let Some(any) = any {
+        emit_error!(any.span, "`any;` is not supported in `mview_ssr!`");
+    }
+
+    if children.slot_children().next().is_none() {
+        if let Some(html) = static_html(children.node_children()) {
+            return with_setup(
+                &provides,
+                &listeners,
+                templates,
+                quote! { ::std::string::String::from(#html) },
+            );
+        }
+    }
+
+    let view = view_tokens(children);
+    with_setup(
+        &provides,
+        &listeners,
+        templates,
+        quote! { ::leptos::tachys::view::RenderHtml::to_html(#view) },
+    )
+}
+
+/// Reads a file of `mview!` syntax and expands it the same as [`mview_impl`].
+///
+/// The path is relative to the crate root (`CARGO_MANIFEST_DIR`), as proc
+/// macros do not know which file they are called from. The file is also
+/// included with `include_str!` so that the crate is rebuilt when it changes.
+#[must_use]
+pub fn include_mview_impl(input: TokenStream) -> TokenStream {
+    proc_macro_error2::set_dummy(quote! { () });
+
+    let path = match syn::parse2::<syn::LitStr>(input) {
+        Ok(path) => path,
+        Err(e) => return e.to_compile_error(),
+    };
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let full_path = std::path::Path::new(&root).join(path.value());
+    let contents = match std::fs::read_to_string(&full_path) {
+        Ok(contents) => contents,
+        Err(e) => abort!(path.span(), "failed to read `{}`: {}", path.value(), e),
+    };
+    let tokens = match contents.parse::<TokenStream>() {
+        Ok(tokens) => tokens,
+        Err(e) => abort!(path.span(), "failed to parse `{}`: {}", path.value(), e),
+    };
+
+    let full_path = full_path.to_string_lossy();
+    let view = mview_impl(tokens);
+    quote_spanned! { path.span()=>
+        {
+            const _: &str = ::std::include_str!(#full_path);
+            #view
+        }
+    }
+}
+
+/// Provides any contexts, adds any global event listeners and defines each
+/// template as a closure before the expression.
+///
+/// String literals that are used more than once are also defined as `const`s,
+/// see [`str_lit_tokens`](ast::str_lit_tokens).
+///
+/// ```text
+/// provide: Theme::Dark;
+/// on:keydown={handler};
+/// template greeting = ("hi");
+/// p(@greeting)
+/// ```
+/// Expands to:
+/// ```text
+/// {
+///     provide_context(Theme::Dark);
+///     { /* see `GlobalListener` */ }
+///     let greeting = || "hi";
+///     p().child(greeting())
+/// }
+/// ```
+fn with_setup(
+    provides: &[Provide],
+    listeners: &[GlobalListener],
+    templates: Vec<Template>,
+    expr: TokenStream,
+) -> TokenStream {
+    let auto_id = new_auto_id_tokens();
+    let provides = quote! { #(#provides)* };
+    let listeners = quote! { #(#listeners)* };
+    let templates = templates
+        .into_iter()
+        .map(|template| {
+            let name = template.name().clone();
+            let view = view_tokens(template.into_children());
+            quote_spanned! { name.span()=> let #name = || #view; }
+        })
+        .collect::<Vec<_>>();
+    // after everything else is expanded, so that every literal is known
+    let str_consts = str_const_tokens();
+    if provides.is_empty()
+        && listeners.is_empty()
+        && templates.is_empty()
+        && auto_id.is_none()
+        && str_consts.is_none()
+    {
+        return expr;
+    }
+
+    quote! {
+        {
+            #str_consts
+            #auto_id
+            #provides
+            #listeners
+            #(#templates)*
+            #expr
+        }
+    }
+}
+
+/// Expands the top-level children of the macro to a view.
+fn view_tokens(children: Children) -> TokenStream {
     // If there's a single top level component, can just expand like
     // div().attr(...).child(...)...
     // If there are multiple top-level children, need to use the fragment.
@@ -43,6 +205,7 @@ pub fn mview_impl(input: TokenStream) -> TokenStream {
                 slot.span(),
                 "slots should be inside a parent that supports slots"
             ),
+            Child::Prop(name, _) => abort!(name.span(), "view props should be inside a component"),
         }
     } else {
         // look for any slots
@@ -52,6 +215,9 @@ pub fn mview_impl(input: TokenStream) -> TokenStream {
                 "slots should be inside a parent that supports slots"
             );
         };
+        if let Some((name, _)) = children.prop_children().next() {
+            abort!(name.span(), "view props should be inside a component");
+        }
 
         let fragment = root_children_tokens(children.node_children(), Span::call_site());
         quote! {
@@ -62,3 +228,128 @@ pub fn mview_impl(input: TokenStream) -> TokenStream {
         }
     }
 }
+
+/// The full input to the macro.
+struct MacroInput {
+    /// A leading `any;` to convert the result into an `AnyView`.
+    any: Option<kw::any>,
+    /// Any `provide: value;` directives after `any;`.
+    provides: Vec<Provide>,
+    /// Any `on:event={handler};` global listeners after the `provide:`s.
+    listeners: Vec<GlobalListener>,
+    /// Any `template name = (...);` definitions after the `on:` listeners.
+    templates: Vec<Template>,
+    children: Children,
+}
+
+/// Parses the macro input, which is a list of children optionally preceded by
+/// `any;`, `provide:` directives, global `on:` listeners and template
+/// definitions, in that order.
+///
+/// An element called `any` is not a valid HTML element (custom elements need a
+/// hyphen), so this does not conflict with any real elements.
+fn parse_input(input: ParseStream) -> syn::Result<MacroInput> {
+    reset_auto_ids();
+    reset_str_consts();
+
+    let any = if input.peek(kw::any) && input.peek2(Token![;]) {
+        let any = kw::any::parse(input)?;
+        <Token![;]>::parse(input)?;
+        Some(any)
+    } else {
+        None
+    };
+
+    let mut provides = Vec::new();
+    while Provide::peek(input) {
+        provides.push(Provide::parse(input)?);
+    }
+
+    let mut listeners = Vec::new();
+    while GlobalListener::peek(input) {
+        listeners.push(GlobalListener::parse(input)?);
+    }
+
+    let mut templates = Vec::new();
+    while Template::peek(input) {
+        templates.push(Template::parse(input)?);
+    }
+
+    let children = match parse_rust_expr(input) {
+        Some(children) => children,
+        None => Children::parse(input)?,
+    };
+    emit_undefined_id_refs();
+
+    Ok(MacroInput {
+        any,
+        provides,
+        listeners,
+        templates,
+        children,
+    })
+}
+
+/// Parses a Rust `match` or `if` expression that makes up the whole input,
+/// which is used as-is as the view:
+/// ```ignore
+/// mview! {
+///     match state.get() {
+///         State::Loading => mview! { "Loading..." }.into_any(),
+///         State::Done(n) => mview! { p({n}) }.into_any(),
+///     }
+/// }
+/// ```
+///
+/// A `match` followed by a block, bracket or literal is the `match` construct
+/// instead, and an `if` followed by other children is an inline `if`, which
+/// are parsed with the rest of the children.
+fn parse_rust_expr(input: ParseStream) -> Option<Children> {
+    let span = input.span();
+    let tokens = if input.peek(Token![match])
+        && !input.peek2(syn::token::Brace)
+        && !input.peek2(syn::token::Bracket)
+        && !input.peek2(syn::Lit)
+    {
+        let tokens = parse_match_tokens(input);
+        if !input.is_empty() {
+            let rest = parse::take_rest(input);
+            emit_error!(
+                rest, "unexpected tokens after the `match` expression";
+                help = "a Rust `match` must be the only thing in the macro, wrap it in \
+                    braces to use it as a child: `{match ...}`"
+            );
+        }
+        tokens
+    } else if input.peek(Token![if]) {
+        let fork = input.fork();
+        let tokens = parse_if_tokens(&fork).ok()?;
+        if !fork.is_empty() {
+            return None;
+        }
+        input.advance_to(&fork);
+        tokens
+    } else {
+        return None;
+    };
+    Some(Children::new(vec![Child::Node(NodeChild::Value(
+        Value::Block {
+            tokens,
+            braces: syn::token::Brace(span),
+        },
+    ))]))
+}
+
+/// Takes the tokens of a `match` expression, up to and including the block with
+/// the arms.
+fn parse_match_tokens(input: ParseStream) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    while !input.is_empty() {
+        let is_arms = input.peek(syn::token::Brace);
+        tokens.extend([TokenTree::parse(input).unwrap()]);
+        if is_arms {
+            break;
+        }
+    }
+    tokens
+}
@@ -1,6 +1,6 @@
 //! Mini helper functions for parsing
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
 use syn::parse::{discouraged::Speculative, Parse, ParseBuffer, ParseStream};
 
 pub fn extract_parenthesized(input: ParseStream) -> syn::Result<(syn::token::Paren, ParseBuffer)> {
@@ -94,3 +94,26 @@ where
 pub fn take_rest(input: ParseStream) -> TokenStream {
     TokenStream::parse(input).expect("parsing TokenStream should never fail")
 }
+
+/// Skips the rest of an element that failed to parse, so that parsing can
+/// continue with the next child.
+///
+/// Stops after the first `;` or children block (braces or parens that are not
+/// an attribute value after a `=`), or at the end of the input.
+pub fn skip_element(input: ParseStream) {
+    let mut after_eq = false;
+    while !input.is_empty() {
+        let tt = TokenTree::parse(input).expect("input is not empty");
+        match &tt {
+            TokenTree::Punct(p) if p.as_char() == ';' => break,
+            TokenTree::Group(g)
+                if !after_eq
+                    && matches!(g.delimiter(), Delimiter::Brace | Delimiter::Parenthesis) =>
+            {
+                break;
+            }
+            _ => {}
+        }
+        after_eq = matches!(&tt, TokenTree::Punct(p) if p.as_char() == '=');
+    }
+}
@@ -75,3 +75,51 @@ use proc_macro_error2::proc_macro_error;
 pub fn mview(input: TokenStream) -> TokenStream {
     leptos_mview_core::mview_impl(input.into()).into()
 }
+
+/// Calls `.to_html()` on the result of [`mview!`], with compile-time static
+/// folding.
+///
+/// Fully static views are rendered to a string literal at compile time.
+/// Anything else expands to the same view as [`mview!`] and is rendered with
+/// `.to_html()` at runtime.
+///
+/// See [module documentation](https://docs.rs/leptos-mview/) for more usage details.
+///
+/// # Examples
+///
+/// ```
+/// # use leptos_mview_macro::mview_ssr; use leptos::prelude::*;
+/// let html: String = mview_ssr! {
+///     footer.site-footer(p("Made with Leptos"))
+/// };
+/// assert_eq!(
+///     html,
+///     r#"<footer class="site-footer"><p>Made with Leptos</p></footer>"#
+/// );
+/// ```
+#[proc_macro_error]
+#[proc_macro]
+pub fn mview_ssr(input: TokenStream) -> TokenStream {
+    leptos_mview_core::mview_ssr_impl(input.into()).into()
+}
+
+/// Expands a file of [`mview!`] syntax, for keeping large templates in their
+/// own files.
+///
+/// The path is relative to the crate root (the directory with `Cargo.toml`),
+/// not the current file.
+///
+/// See [module documentation](https://docs.rs/leptos-mview/) for more usage details.
+///
+/// # Examples
+///
+/// ```ignore
+/// # use leptos_mview_macro::include_mview; use leptos::prelude::*;
+/// // templates/footer.mview: footer.site-footer(p("Made with Leptos"))
+/// let footer = include_mview!("templates/footer.mview");
+/// ```
+#[proc_macro_error]
+#[proc_macro]
+pub fn include_mview(input: TokenStream) -> TokenStream {
+    leptos_mview_core::include_mview_impl(input.into()).into()
+}
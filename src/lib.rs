@@ -120,13 +120,23 @@ The below are the versions with which I have tested it to be working. It is like
 
 This crate also has a feature `"nightly"` that enables better proc-macro diagnostics (simply enables the nightly feature in proc-macro-error2. Necessary while [this pr](https://github.com/GnomedDev/proc-macro-error-2/pull/5) is not yet merged).
 
+The `"inert-html"` feature renders elements that are completely static (only string literal attributes and children, no directives, blocks or components) to a HTML string at compile time, which is then used with Leptos' `InertElement`. This avoids building the static elements at runtime, which is useful for large static sections like headers and footers. Only nested elements are rendered this way, as the top-level element may still have attributes added to it.
+
+The `"lints"` feature warns about brackets that only contain a literal in HTML attributes (like `value=["static"]`), which adds an unnecessary closure. The literal can be used directly instead (`value="static"`). These warnings are only shown with the `"nightly"` feature.
+
+The `"strict"` feature checks string literal values of enumerated HTML attributes, which only accept a fixed set of values (like `loading`, `decoding`, `crossorigin` or `dir`). A typo like `loading="lazyy"` is a compile error instead of being silently ignored by the browser.
+
+The `"trim-whitespace"` feature skips string literal children that only contain (ASCII) whitespace, like `" "` or `"\n  "`. This is useful if whitespace is added around children while formatting, but should not be in the rendered HTML. Use the `space` or `nbsp` keywords for whitespace that should always be kept.
+
+The `"source-locations"` feature adds a `data-mview-loc="file:line"` attribute to every HTML, SVG and MathML element, with the location of its tag in the source. This makes it easy to find where an element in the browser's dev tools was written. The attributes are only added in debug builds (`cfg(debug_assertions)`), and the `"inert-html"` feature has no effect while this is enabled.
+
 # Syntax details
 
 ## Elements
 
 Elements have the following structure:
 
-1. Element / component tag name / path (`div`, `App`, `component::Codeblock`).
+1. Element / component tag name / path (`div`, `App`, `component::Codeblock`). Paths can start with `crate::`, `super::` or `self::`, the same as in Rust.
 2. Any classes or ids prefixed with a dot `.` or hash `#` respectively.
 3. A space-separated list of attributes and directives (`class="primary"`, `on:click={...}`).
 4. Children in parens or braces (`("hi")` or `{ "hi!" }`), or a semi-colon for no children (`;`).
@@ -144,6 +154,10 @@ mview! {
 # ;
 ```
 
+Components from other crates are used the same way, like the document head components from `leptos_meta` (`Title text="My page";`, `Meta name="description" content="...";`, `Stylesheet id="main" href="/pkg/app.css";`, `Link rel="icon" href="/favicon.ico";` or `leptos_meta::Html attr:lang="en";`). A lowercase `title`, `meta`, `link`, `html` or `body` is always the HTML element, so there is no lowercase shorthand for these components.
+
+Tags with a hyphen, like `my-widget` or `iconify-icon`, are [custom elements](https://developer.mozilla.org/en-US/docs/Web/API/Web_components/Using_custom_elements) (web components). They can have selectors, attributes and directives like any other element: `prop:` is useful for passing values that are not strings to the custom element's properties. Shadow DOM attributes like `part`, `exportparts` and `slot="name"` are passed through as-is: only `slot:` (with a colon) is a [Leptos slot](#slots).
+
 Adding generics is the same as in Leptos: add it directly after the component name, with or without the turbofish.
 
 ```
@@ -165,6 +179,8 @@ pub fn App() -> impl IntoView {
 }
 ```
 
+Const generic arguments also work, like `Grid<3>` or `Grid<{ SIZE * 2 }>`. Expressions other than literals and paths need to be wrapped in braces, the same as in Rust.
+
 Note that due to [Reserving syntax](https://doc.rust-lang.org/edition-guide/rust-2021/reserving-syntax.html), the `#` for ids must have a space before it.
 
 ```
@@ -178,6 +194,23 @@ mview! {
 
 Classes/ids created with the selector syntax can be mixed with the attribute `class="..."` and directive `class:a-class={signal}` as well.
 
+Selectors also work on components: they are forwarded to the component's root element(s), in the same way as `attr:class`/`attr:id`.
+
+```
+# use leptos_mview::mview; use leptos::prelude::*;
+#[component]
+fn Card() -> impl IntoView {
+    mview! { div.card("...") }
+}
+
+# fn _a() -> impl IntoView {
+mview! {
+    // renders <div id="main-card" class="card highlighted">...</div>
+    Card.highlighted #main-card;
+}
+# }
+```
+
 There is also a special element `!DOCTYPE html;`, equivalent to `<!DOCTYPE html>`.
 
 ## Slots
@@ -232,9 +265,54 @@ pub fn App() -> impl IntoView {
 # }
 ```
 
+A slot can be left out by adding `when={condition}`. The condition is checked once when the component is created, and the slot is passed as `Some(slot)` or `None`, so the component must take an `Option` of the slot without stripping it (`#[prop(optional_no_strip)]` or `#[prop(default = None)]`). If there are multiple slots of the same name, the excluded ones are removed from the `Vec` instead. Note that this means slots cannot have a prop called `when`.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+# #[slot] struct Fallback { children: ChildrenFn }
+#[component]
+fn Page(
+    children: ChildrenFn,
+    #[prop(optional_no_strip)] fallback: Option<Fallback>,
+) -> impl IntoView {
+    // ...
+#     let _ = fallback; children()
+}
+
+let logged_in = false;
+mview! {
+    Page {
+        "content"
+        slot:Fallback when={!logged_in} { "please log in" }
+    }
+}
+# ;
+```
+
+### View props
+
+Props that take a [`ViewFn`](https://docs.rs/leptos/latest/leptos/children/struct.ViewFn.html) (like a `view`, `render` or `fallback` prop) can be passed in the children as `name: (...)`, instead of `name=[mview! { ... }]`. These can be mixed with the other children and slots, and any `clone:`s on the component also apply to them.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+#[component]
+fn Card(#[prop(into)] title: ViewFn, children: Children) -> impl IntoView {
+    mview! { article(header({title.run()}) children) }
+}
+
+# fn _a() -> impl IntoView {
+mview! {
+    Card {
+        title: ( h2("Welcome") )
+        p("Some content")
+    }
+}
+# }
+```
+
 ## Values
 
-There are (currently) 3 main types of values you can pass in:
+There are (currently) 4 main types of values you can pass in:
 
 - **Literals** can be passed in directly to attribute values (like `data=3`, `class="main"`, `checked=true`).
     - However, children do not accept literal numbers or bools - only strings.
@@ -244,6 +322,8 @@ There are (currently) 3 main types of values you can pass in:
         mview! { p("this works " 0 " times: " true) }
         # ;
         ```
+    - Any kind of string literal can be used, including raw strings (`r#"{"key": "value"}"#`) and strings that span multiple lines, which is useful for embedding JSON or CSS.
+    - Number literals can use any Rust syntax, including negative numbers, `_` separators and suffixes (like `tabindex=-1` or `data-count=1_000`).
 
 - Everything else must be passed in as a **block**, including variables, closures, or expressions.
     ```
@@ -285,7 +365,11 @@ There are (currently) 3 main types of values you can pass in:
     # ;
     ```
 
-    - Note that this always expands to `move || ...`: for any closures that take an argument, use the full closure block instead.
+    - The brackets can contain multiple statements, the same as a closure body: `[let doubled = number() * 2; doubled + 1]` expands to `{move || { let doubled = number() * 2; doubled + 1 }}`.
+
+    - The closure is not required to be `Fn`: Leptos accepts `FnMut` closures for children and attribute values, so a bracket can mutate state it captures (like `[{ renders += 1; renders }]`).
+
+    - Note that a plain bracket always expands to `move || ...`: for closures that take an argument (like event handlers), put the argument pattern before a `=>`. `on:click=[ev => handle(ev)]` expands to `on:click={move |ev| { handle(ev) }}`, and `[_ => count.set(0)]` ignores the argument. Without the `=>`, it does not compile:
         ```compile_error
         # use leptos_mview::mview;
         # use leptos::logging::log;
@@ -299,13 +383,35 @@ There are (currently) 3 main types of values you can pass in:
         # use leptos_mview::mview; use leptos::prelude::*;
         # use leptos::logging::log;
         mview! {
-            input type="text" on:click={|_| log!("THIS WORKS!")};
+            input type="text" on:click=[_ => log!("THIS WORKS!")];
+            // or with the full closure
+            input type="text" on:click={|_| log!("THIS ALSO WORKS!")};
         }
         # ;
         ```
 
+- An **`if` expression** can be used directly as a value, without any brackets. This is the same as wrapping it in brackets, so it will rerun when any signals in it change.
+    ```
+    # use leptos::prelude::*; use leptos_mview::mview;
+    let active = RwSignal::new(true);
+    mview! {
+        button
+            class=if active.get() { "on" } else { "off" } // `[if active.get() { "on" } else { "off" }]`
+            on:click={move |_| active.update(|a| *a = !*a)}
+        (
+            if active.get() { "Turn off" } else { "Turn on" }
+        )
+    }
+    # ;
+    ```
+
+- A **`!`** before a block or bracket negates it in a closure, which is handy for boolean attributes. `hidden=!{is_visible}` is the same as `hidden={move || !is_visible()}` (the block is called), and `hidden=![visible.get()]` is the same as `hidden=[!visible.get()]`.
+
 The bracketed values can also have some special prefixes for even more common shortcuts!
-- Currently, the only one is `f` - e.g. `f["{:.2}", stuff()]`. Adding an `f` will add `format!` into the closure. This is equivalent to `[format!("{:.2}", stuff())]` or `{move || format!("{:.2}", stuff())}`.
+- `f` - e.g. `f["{:.2}", stuff()]`. Adding an `f` will add `format!` into the closure. This is equivalent to `[format!("{:.2}", stuff())]` or `{move || format!("{:.2}", stuff())}`.
+- `p` - e.g. `p["rendered {}", count()]`. Adding a `p` will log the arguments with [`leptos::logging::log!`](https://docs.rs/leptos/latest/leptos/logging/macro.log.html) whenever the closure is run, and render nothing. This is handy for quickly checking when part of a view rerenders. As an event handler, like `on:click=p["clicked"]`, it logs every time the event fires (the event itself is ignored).
+- `classes` - e.g. `classes["btn", active => is_active(), size()]`. This builds a space-separated string of classes. Plain entries are always added, and `name => condition` entries are only added if the condition is true. The name can be a string or a kebab-case identifier (`is-active => ...`). This is equivalent to `[format!("btn{} {}", if is_active() { " active" } else { "" }, size())]`, without the extra spaces.
+- `s` - e.g. `s["display: flex", color: color(), --gap: format!("{}px", gap())]`. This builds a style string, joining the entries with `; `. `property: value` entries use the `Display` of the value, and plain entries are added as-is. Like `classes`, the property can be a string or a kebab-case identifier, including custom properties (`--gap`).
 
 ## Attributes
 
@@ -344,8 +450,82 @@ Most attributes are `key=value` pairs. The `value` follows the rules from above.
     # ;
     ```
 
+    If the names are different, `{value as name}` is the same as `name={value}`. This is useful when a variable's name nearly matches a prop:
+    ```
+    # use leptos_mview::mview; use leptos::prelude::*;
+    # #[component] fn Header(title: String) -> impl IntoView {}
+    let page_title = String::from("Home");
+    mview! {
+        Header {page_title as title};
+    }
+    # ;
+    ```
+
     See also: [kebab-case identifiers with attribute shorthand](#kebab-case-identifiers-with-attribute-shorthand)
 
+Attributes on HTML elements are rendered in the same order as they are written, so server-rendered HTML is deterministic. The only exceptions are ids from selectors, which are placed after the other attributes, and `class` and `style`, which are merged from all sources (selectors, attributes and directives) and always rendered last.
+
+```
+# use leptos_mview::mview; use leptos::prelude::*;
+mview! {
+    // renders <a href="#" title="home" class="nav active">...</a>
+    a.active class="nav" href="#" title="home" ("home")
+}
+# ;
+```
+
+Classes that are known at compile time (selectors and string `class="..."` attributes) are merged into a single class string by the macro, before any `class:` directives. So `div.a.b class="c" class:d=true` renders `class="c a b d"`. This is skipped if there is also a dynamic `class={...}` attribute. In the same way, a `style="..."` attribute is combined with any `style:` directives into a single `style` attribute, with the string first: `div style="color: red" style:width=[w.get()]` renders `style="color: red;width:10px;"`.
+
+An attribute with an `Option` value is skipped if the value is `None`. Adding a `?` before the value (`key=?{value}` or `key=?[value]`) makes this explicit, and gives an error if the value is not an `Option`. This works with `class` too, which is merged with any selector classes:
+
+```
+# use leptos_mview::mview; use leptos::prelude::*;
+let extra_class: Option<&str> = Some("highlighted");
+let title: Option<&str> = None;
+mview! {
+    // renders <div class="highlighted card">...</div>
+    div.card class=?{extra_class} title=?{title} ("card")
+}
+# ;
+```
+
+Expensive attributes that are only needed on the client can be deferred with `key=lazy{value}`. The value is computed in an [`Effect`](https://docs.rs/leptos/latest/leptos/prelude/struct.Effect.html), so it is never computed on the server, and is only computed after the view is hydrated (or mounted) on the client. Until then, the attribute is not set. If the value uses any signals, the effect reruns and updates the attribute when they change. The value must be `Send + Sync`, as it is stored in a signal.
+
+```
+# use leptos_mview::mview; use leptos::prelude::*;
+# fn expensive_tooltip() -> String { String::new() }
+mview! {
+    // renders <div>hover me</div> on the server
+    div title=lazy{expensive_tooltip()} ("hover me")
+}
+# ;
+```
+
+On components, a prop that takes a struct can be built from its fields with `name:{ field=value ... }`. The struct is created with `Default::default()`, then each field is set, so the struct must implement [`Default`] and the fields must be visible. The fields are written like attributes, so shorthands and boolean fields work too. The first field must be a `field=value`, to tell it apart from a directive like `class:{primary}`.
+
+```
+# use leptos_mview::mview; use leptos::prelude::*;
+#[derive(Default)]
+struct ChartConfig { width: u32, height: u32, animated: bool }
+
+#[component]
+fn Chart(config: ChartConfig) -> impl IntoView {
+    // ...
+#   let _ = config;
+}
+
+let height = 200;
+mview! {
+    Chart config:{ width=300 {height} animated };
+}
+# ;
+// same as...
+mview! {
+    Chart config={ChartConfig { width: 300, height, animated: true, ..Default::default() }};
+}
+# ;
+```
+
 Note that the special `node_ref` or `ref` or `_ref` or `ref_` attribute in Leptos to bind the element to a variable is just `ref={variable}` in here.
 
 ### Boolean attributes
@@ -364,21 +544,43 @@ mview! { LotsOfFlags wide=true tall=true red=false curvy=true count=3; }
 # ;
 ```
 
+The macro does not know the types of props, so the shorthand always passes `true`. Writing `count` without a value on a prop that is not a `bool` gives a type error at `count`.
+
 See also: [boolean attributes on HTML elements](#boolean-attributes-on-html-elements)
 
 ### Directives
 
 Some special attributes (distinguished by the `:`) called **directives** have special functionality. All have the same behaviour as Leptos. These include:
-- `class:class-name=[when to show]`
-- `style:style-key=[style value]`
-- `on:event={move |ev| event handler}`
-- `prop:property-name={signal}`
-- `attr:name={value}`
-- `clone:ident_to_clone`
-- `use:directive_name` or `use:directive_name={params}`
-- `bind:checked={rwsignal}` or `bind:value={(getter, setter)}`
+- `class:class-name=[when to show]`, or `class:{name_expr}=[when to show]` if the class name is a constant expression (like `class:{concat!("btn-", "primary")}=true`). Use `class:{..names}=[when to show]` to toggle several classes with one condition, where `names` is any iterator of `&'static str` (like `const NAMES: [&str; 3]`). The condition must be `Clone`, as it is shared by every class.
+- `style:style-key=[style value]`, or `style:{name_expr}=[style value]`. Use `style:style-key=?[maybe_value]` with an `Option` value to remove the style while the value is `None`.
+- `on:event={move |ev| event handler}`, where `ev` is the typed event from [`leptos::ev`](https://docs.rs/leptos/latest/leptos/ev/index.html) (like a `TransitionEvent` for `on:transitionend`). Several handlers can be combined with [`handlers!`], like `on:click={handlers![log_click, track]}`, which calls each handler in order.
+- `prop:property-name={signal}`. Use `prop:property-name=?[maybe_value]` with an `Option` value to only set the property while the value is `Some`. Changing it to `None` resets the property to `undefined`, which is useful for switching an input between controlled and uncontrolled.
+- `attr:name={value}`, or `attr:{name_expr}={value}` if the attribute name is only known at runtime (this also works on HTML elements). The name can be any expression, like `attr:{if big { "data-big" } else { "data-small" }}` or a `match` on an enum. Use a string name like `attr:"on:custom"="value"` to add an attribute whose name would otherwise be read as a directive; the string is used as the attribute name as-is.
+- `clone:ident_to_clone`, or `clone:self.field` to clone a field into a variable named after the last field (`let field = self.field.clone();`). This is useful when building views in methods, as `&self` cannot be moved into the children closure. Clones are also added to bracket attributes that use them, as these are closures too (like `Show clone:name when=[!name.is_empty()]`).
+- `use:directive_name` or `use:directive_name={params}`. Use `use:directive_name=?{maybe_params}` to only apply the directive if the params are `Some`; a `None` skips the directive entirely.
+- `bind:checked={rwsignal}` or `bind:value={(getter, setter)}`. `bind:` can only be used on `input`, `select` and `textarea` elements (and custom elements), other HTML elements give a compile error.
+- `bind:number={rwsignal}`: like `bind:value`, but for signals of numbers (or anything that implements `FromStr` and `Display`). The input is parsed on every `input` event, and the signal is left unchanged if parsing fails.
+
+If many components need the same clones, wrap them in a `with { clone: a; clone: b; } (...)` block. This adds `clone:a clone:b` to every component and slot inside the block that uses them (in its attributes or children, including nested ones), and renders the children as if the block was not there. Closures written directly in blocks (like `{move || a.len()}`) are not changed, so clone into those manually.
 
-All of these directives except `clone` also support the attribute shorthand:
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let user = String::from("Ferris");
+let theme = String::from("dark");
+mview! {
+    with { clone: user; clone: theme; } (
+        Show when=[true] {
+            p({user.clone()})
+            Show when=[true] { {user.clone()} " uses " {theme.clone()} }
+        }
+    )
+}
+# ;
+```
+
+The values of directives can be any expression, like `prop:value={self.state.value}`.
+
+All of these directives except `clone` also support the attribute shorthand (which only takes an identifier, like `prop:{value}`):
 
 ```
 # use leptos::prelude::*; use leptos_mview::mview;
@@ -402,8 +604,64 @@ mview! {
 # ;
 ```
 
+CSS custom properties (variables) can be set with `style:` like any other style, as the leading `--` is part of the name.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let theme = RwSignal::new("rebeccapurple");
+mview! {
+    div style:--theme-color=[theme.get()] style:color="var(--theme-color)";
+}
+# ;
+```
+
 Note that the `use:` directive automatically calls `.into()` on its argument, consistent with behaviour from Leptos.
 
+The `class:`, `style:`, `attr:`, `prop:`, `on:` and `use:` directives can also be used on components. They are forwarded to the component's root element(s), the same as Leptos' attribute spreading.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+#[component]
+fn Card() -> impl IntoView {
+    mview! { div.card("...") }
+}
+
+# fn _a() -> impl IntoView {
+let active = RwSignal::new(true);
+mview! {
+    // renders <div class="card active" style="color:red;">...</div>
+    Card class:active=[active.get()] style:color="red";
+}
+# }
+```
+
+For controlled inputs, the value of an `on:` directive can also be a tuple of an extractor and a setter: `on:input=(event_target_value, set_value)` is the same as `on:input={move |ev| set_value.set(event_target_value(&ev).into())}`. The extractor is called with a reference to the event, and the setter can be any signal (anything that implements [`Set`](https://docs.rs/leptos/latest/leptos/prelude/trait.Set.html)).
+
+The `on:` directive also supports some modifiers after the event name:
+- `on:click:undelegated={handler}` adds the event listener directly to the element instead of using event delegation (when Leptos' `delegation` feature is enabled). This is useful for widgets from other libraries that stop events from propagating to the document. The handler still receives the typed event.
+- `on:click:capture={handler}` handles the event during the capture phase, before any listeners on child elements.
+- `on:scroll:passive={handler}` adds a [passive](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener#passive) event listener, which can improve scrolling performance for `scroll`, `wheel` and `touch*` events. The handler should not call `prevent_default`.
+- `on:input:value={handler}` calls the handler with the `event_target_value` of the event (a `String`), instead of the event itself.
+- `on:input:set={setter}` sets a signal (like a `WriteSignal` or `RwSignal`) to the `event_target_value` of the event. The value is converted with `.into()`, so signals of other string types like `Option<String>` or `Arc<str>` also work.
+- `on:submit:prevent_if={handler}` calls `prevent_default` on the event if the handler returns `true`.
+- `on:click:async={handler}` takes a handler that returns a future (like `|ev| async move { ... }`), and runs the future with [`spawn_local`](https://docs.rs/leptos/latest/leptos/task/fn.spawn_local.html).
+- `on:click:log={handler}` logs the event with [`leptos::logging::log!`](https://docs.rs/leptos/latest/leptos/logging/macro.log.html) before running the handler, for tracing which events fire. The logging is only added in debug builds (`cfg(debug_assertions)`), so this is the same as `on:click={handler}` in release builds.
+- `on:click:self={handler}` only runs the handler if the event target is the element itself (`ev.target() == ev.current_target()`), not one of its children. This is useful for closing a modal when its backdrop is clicked.
+- `on:click:spa={handler}` intercepts a link for client-side routing: it calls `prevent_default` on the event and passes the resolved (absolute) `href` of the `a` element to the handler as a `String`, like `a href="/about" on:click:spa={move |href| navigate(&href)}`. Nothing happens if the listener is not on an `a` element.
+- `on:keydown:enter={handler}` only runs the handler if the [`key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key) of the event is `Enter`. The supported keys are `:enter`, `:escape`, `:tab`, `:space` and the arrow keys `:up`, `:down`, `:left` and `:right`. These can only be used with keyboard events (like `keydown` and `keyup`).
+- `on:change:target::<HtmlInputElement>={move |el, ev| ...}` passes the event target, cast to the given element type, as the first argument to the handler, before the event. The cast is unchecked (the same as Leptos' `event_target`), so the type must match the element that fires the event.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let (name, set_name) = signal(String::new());
+mview! {
+    form on:submit:prevent_if={move |_| name.get().is_empty()} {
+        input type="text" prop:value={name} on:input:set={set_name};
+    }
+}
+# ;
+```
+
 ## Children
 
 You may have noticed that the `let:data` prop was missing from the previous section on directive attributes!
@@ -425,6 +683,22 @@ mview! {
 
 Note that you will usually need to add a `*` before the data you are using. If you forget that, rust-analyser will tell you to dereference here: `*{monkeys}`. This is obviously invalid - put it inside the braces.
 
+The arguments can be anything the component passes to its children, including functions that render a view (often called render props). These are called like any other function in a block, like `Toolbar |button| ( {button("save")} {button("open")} )`.
+
+For compatibility with components documented using Leptos' syntax, a single `let:binding` directive is also accepted on components and slots. It is the same as writing `|binding|` before the children, but the closure is still recommended as it supports multiple arguments and patterns.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+#[component]
+fn Numbers() -> impl IntoView {
+    mview! {
+        For each=[[1, 2, 3]] key={|n| *n} let:number (
+            p("number " {number})
+        )
+    }
+}
+```
+
 Children can be wrapped in either braces or parentheses, whichever you prefer.
 
 ```
@@ -437,10 +711,421 @@ mview! {
 # ;
 ```
 
+As parentheses are children, a component like `MyComponent({value})` is given `{value}` as its children, not as a prop. Components only take named props (`MyComponent value={value}`), so comma-separated arguments like `MyComponent(1, 2)` are a compile error.
+
+A block child always needs its own braces, even if it is the only child: write `Comp({count + 1})` or `Comp { {count + 1} }`. `Comp {count}` is the [attribute shorthand](#kebab-case-identifiers-with-attribute-shorthand) for `count={count}`, not a child.
+
 Summary from the previous section on values in case you missed it: children can be literal strings (not bools or numbers!), blocks with Rust code inside (`{*monkeys}`), or the closure shorthand `[number() + 1]`.
 
 Children with closures are also supported on slots.
 
+When writing a wrapper or layout component, a bare `children` can be used as a shorthand for `{children()}`. This calls whatever `children` is in scope, so it works with `Children`, `ChildrenFn` and `ChildrenFnMut`. Use the full block if the children need to be passed around differently (like `{children.clone()}`).
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+#[component]
+fn Layout(children: Children) -> impl IntoView {
+    mview! {
+        main.layout(children)
+    }
+}
+```
+
+An existing closure can be passed as the children with a `children={closure}` attribute, which is converted the same way as a children block (so it works with `Children`, `ChildrenFn`, etc.). This cannot be combined with a children block. A closure can also be used as a block child (`Wrapper({closure})`), which is rendered by calling it.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+#[component]
+fn Wrapper(children: Children) -> impl IntoView {
+    mview! { div.wrapper(children) }
+}
+
+let content = || mview! { p("existing content") };
+mview! {
+    Wrapper children={content};
+}
+# ;
+```
+
+Components that need to handle each child separately (like a splitter with exactly two panes) can take `children: Vec<AnyView>`, and be given a `collect:children` directive. Each child is converted with `.into_any()` and passed in a `Vec` instead of a single `Children`, so the component can count or wrap them. The `Vec` is passed even if there are no children, and children closure arguments are not supported.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+#[component]
+fn Splitter(children: Vec<AnyView>) -> impl IntoView {
+    let panes = children.into_iter().map(|pane| mview! { div.pane({pane}) });
+    mview! { div.splitter({panes.collect_view()}) }
+}
+
+mview! {
+    Splitter collect:children (
+        p("left")
+        p("right")
+    )
+}
+# ;
+```
+
+Whitespace between inline elements has to be written in a string. To make it more obvious, a bare `space` is a text node with a single space, and `nbsp` is a non-breaking space (`&nbsp;`).
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+mview! {
+    // same as p(strong("Name:") " " em("mview") "\u{a0}" "0.4")
+    p(strong("Name:") space em("mview") nbsp "0.4")
+}
+# ;
+```
+
+## Error boundaries
+
+`err_boundary` is a shorthand for Leptos' [`ErrorBoundary`](https://docs.rs/leptos/latest/leptos/error/fn.ErrorBoundary.html). Any children that are a `Result` render their `Ok` value, and the `fallback` is shown if there are any `Err`s.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let number: Result<i32, std::num::ParseIntError> = "abc".parse();
+mview! {
+    err_boundary fallback=[mview! { p.error("Not a number!") }] (
+        "The number is " {number}
+    )
+}
+# ;
+```
+
+A bracketed `fallback` ignores the errors given to the fallback. To use them, pass a closure instead: `fallback={|errors| ...}`.
+
+## Loops
+
+`for {pattern} in {iterator} (...)` renders the children once for each item. This is a plain loop that is rendered once, like calling `.map(...).collect_view()`: use [`For`](https://docs.rs/leptos/latest/leptos/control_flow/fn.For.html) for keyed, efficiently updating lists.
+
+An `else (...)` branch can be added to render something else when the iterator is empty. This works for lazy iterators as well, but the first item is taken from the iterator before anything is rendered to check if it is empty.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let fruits = vec!["apple", "banana"];
+mview! {
+    ul {
+        for {(i, fruit)} in {fruits.into_iter().enumerate()} (
+            li(f["{}. {fruit}", i + 1])
+        ) else (
+            li.empty("No fruits")
+        )
+    }
+}
+# ;
+```
+
+If the iterator is in brackets (like `for {n} in [numbers.get()] (...)`), the whole loop is wrapped in a `move ||` closure, so it is rerendered whenever the signals used in the brackets change.
+
+Adding a `key={...}` after the iterator uses a keyed [`For`](https://docs.rs/leptos/latest/leptos/control_flow/fn.For.html) instead, which only rerenders the items that changed. The iterator can be a signal of a collection (like `Signal<Vec<T>>`), which is read with `.get()`, or a bracket that is used as the `each` closure. An `else` branch is not supported with a `key`.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+#[derive(Clone)]
+struct Todo { id: u32, name: String }
+
+#[component]
+fn Todos(todos: RwSignal<Vec<Todo>>) -> impl IntoView {
+    mview! {
+        ul {
+            for {todo} in {todos} key={|todo| todo.id} (
+                li({todo.name})
+            )
+        }
+    }
+}
+```
+
+If you already have an iterator of views, `each(...)` renders it as a child by calling [`.collect_view()`](https://docs.rs/leptos/latest/leptos/prelude/trait.CollectView.html) on it. As with `for`, a bracket (like `each([...])`) is rerendered whenever its signals change.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+mview! {
+    ol {
+        each({(1..=3).map(|i| mview! { li(f["step {i}"]) })})
+    }
+}
+# ;
+```
+
+Use `each?(...)` if the iterator is in an `Option`, to render the list when it is `Some` and nothing when it is `None`.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let steps: Option<Vec<&str>> = None;
+mview! {
+    ol {
+        each?({steps.map(|steps| steps.into_iter().map(|s| mview! { li({s}) }))})
+    }
+}
+# ;
+```
+
+## Match
+
+`match {value} { pattern => (...) }` renders the children of the arm that matches the value. Each arm can have any number of children, which are rendered together, so this works well for states that need a different set of elements. Arms can be separated with commas, and patterns can have guards like in Rust. As with `for`, a bracket (like `match [state.get()] {...}`) is rerendered whenever its signals change.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+#[derive(Clone, Copy)]
+enum State { Loading(u8), Done { items: usize } }
+
+let state = RwSignal::new(State::Loading(10));
+mview! {
+    div {
+        match [state.get()] {
+            State::Loading(percent) => (
+                progress max="100" value={percent};
+                span("Loading...")
+            )
+            State::Done { items } => (
+                h2("Done")
+                p(f["{items} items"])
+            )
+        }
+    }
+}
+# ;
+```
+
+If the whole macro is a Rust `match` (the value is not in a block, bracket or literal) or `if`, it is used as-is as the view. Each arm needs to return the same type, like an `AnyView`:
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let count: Option<usize> = Some(3);
+mview! {
+    match count {
+        Some(n) => mview! { p(f["{n} items"]) }.into_any(),
+        None => "Loading...".into_any(),
+    }
+}
+# ;
+```
+
+Nothing else can come after a `match`, and an `if` that is followed by other children is an [inline `if`](#values) instead. The expression is only run once, so wrap it in a bracket like `[match state.get() { ... }]` to rerender when its signals change.
+
+## Live regions
+
+`live_region(politeness) (...)` creates a `div` with `aria-live` and `aria-atomic="true"`, so that screen readers announce changes to its children. The politeness must be `polite`, `assertive` or `off`. Selectors and other attributes can be added like on any element.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let status = RwSignal::new("Saved");
+mview! {
+    live_region(polite).status ( [status.get()] )
+}
+# ;
+```
+
+## Hiding without unmounting
+
+`show_hidden [when] (...)` is like [`Show`](https://docs.rs/leptos/latest/leptos/control_flow/fn.Show.html), but hides its children with the [`hidden`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/hidden) attribute instead of removing them. The elements are kept, so input values, focus and scroll positions are preserved when they are toggled. The condition can be a bracket or a block with something to call (like `{is_open}`), and every child must be an element or a component (which gets `attr:hidden`). The children are rendered directly in the parent, without a wrapper.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let editing = RwSignal::new(false);
+mview! {
+    button on:click={move |_| editing.update(|e| *e = !*e)} ("Edit")
+    show_hidden [editing.get()] (
+        textarea placeholder="Draft";
+    )
+}
+# ;
+```
+
+## Animations
+
+Enter and leave animations use Leptos' [`AnimatedShow`](https://docs.rs/leptos/latest/leptos/prelude/fn.AnimatedShow.html) like any other component. The children are wrapped in a `div` with the `show_class`, which changes to the `hide_class` for `hide_delay` before they are unmounted.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+# use std::time::Duration;
+let saved = RwSignal::new(false);
+mview! {
+    AnimatedShow
+        when={saved}
+        show_class="fade-in"
+        hide_class="fade-out"
+        hide_delay={Duration::from_millis(300)}
+    {
+        div.toast("Saved")
+    }
+}
+# ;
+```
+
+There is no `transition_group` for lists, as Leptos does not delay removing the items of a `For`. Items can still animate in with a CSS class on each item.
+
+## Generated ids
+
+Use `#auto(name)` instead of an id selector to give an element a generated id, and `@ref(name)` as a value anywhere else in the same macro to get that id. This is useful for ARIA relationships like `aria-labelledby`, without having to come up with unique ids by hand. The reference can come before or after the element, and a reference without a matching `#auto` is an error.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+mview! {
+    section aria-labelledby=@ref(title) {
+        h2 #auto(title) ("Settings")
+        input type="checkbox" aria-describedby=@ref(hint);
+        p #auto(hint) ("Changes are saved automatically")
+    }
+}
+# ;
+```
+
+The ids are made from the name and a number that is taken every time the view is built (like `mview-title-3`), so every instance of a component has its own ids. An `#auto` inside the body of a `for` loop gives each item its own id, so it can only be referenced inside the loop, where `@ref` matches within the same item. Ids from outside the loop can be referenced from inside it, and are the same for every item.
+
+## Resources
+
+`resource {res} loading=[...] |data| (...)` reads a resource inside a [`Suspense`](https://docs.rs/leptos/latest/leptos/suspense/fn.Suspense.html). The `loading` fallback is shown until the resource has loaded, then the children are rendered with the data passed to the closure arguments. The closure arguments can be left out if the data is not needed.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+# async fn fetch_name(id: u32) -> String { format!("user {id}") }
+# fn _a() -> impl IntoView {
+let id = RwSignal::new(1);
+let user = Resource::new(move || id.get(), fetch_name);
+mview! {
+    resource {user} loading=["Loading..."] |name| (
+        h1("Hello, " {name})
+    )
+}
+# }
+```
+
+This is the same as reading the resource in a `Suspend` in the children of a `Suspense`. Any other attributes are passed on to the `Suspense`, and any value that is `Clone` and [`IntoFuture`](std::future::IntoFuture) can be used instead of a resource.
+
+## Pre-escaped text
+
+Strings in children are always escaped. If you already have escaped HTML (like `"Fish &amp; chips"`), use `raw_text(...)` to render it as-is. Leptos has no pre-escaped text node, so this renders a `span` with its `inner_html` set to the value.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let escaped = "Fish &amp; chips";
+mview! {
+    // <p>Menu: <span>Fish &amp; chips</span></p>
+    p("Menu: " raw_text({escaped}))
+}
+# ;
+```
+
+As with `inner_html`, make sure the content is sanitized to avoid cross-site scripting (XSS) vulnerabilities.
+
+## Returning typed elements
+
+If the macro has a single HTML, SVG or MathML element at the top level, the result is that element's concrete [`HtmlElement`](https://docs.rs/leptos/latest/leptos/tachys/html/element/struct.HtmlElement.html) type, the same as building it with Leptos' builder syntax. This means more attributes or a `node_ref` can be added to it afterwards, which is useful for libraries that build on views made with `mview!`.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+use leptos::html::{self, HtmlElement};
+
+fn card(title: &'static str) -> HtmlElement<html::Div, impl leptos::attr::Attribute, impl RenderHtml> {
+    mview! { div.card(h2({title})) }
+}
+
+let card = card("hi").id("first").attr("data-index", "1");
+# ;
+```
+
+## Returning an `AnyView`
+
+Starting the macro with `any;` converts the whole view into an [`AnyView`](https://docs.rs/leptos/latest/leptos/prelude/type.AnyView.html), the same as calling `.into_any()` on the result. This is useful for returning different views from branches or storing views in a collection.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+let views: Vec<AnyView> = vec![
+    mview! { any; p("a paragraph") },
+    mview! { any; input type="checkbox"; "and some text" },
+];
+```
+
+## Providing context
+
+`provide: value;` at the start of the macro calls [`provide_context(value)`](https://docs.rs/leptos/latest/leptos/context/fn.provide_context.html) before the view is built, so components in the view can read it with `use_context`. Multiple `provide:`s can be used, and they come after `any;` and before any templates.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+#[derive(Clone)]
+struct Theme(&'static str);
+
+#[component]
+fn ThemedButton() -> impl IntoView {
+    let theme = use_context::<Theme>().map_or("light", |t| t.0);
+    mview! { button class={theme} ("click") }
+}
+
+# fn _a() -> impl IntoView {
+mview! {
+    provide: Theme("dark");
+    div { ThemedButton; }
+}
+# }
+```
+
+The context is provided to the current owner (usually the component calling `mview!`), the same as calling `provide_context` just before the macro.
+
+## Global event listeners
+
+`on:event={handler};` at the start of the macro adds an event listener to the `window` with [`window_event_listener`](https://docs.rs/leptos/latest/leptos/prelude/fn.window_event_listener.html), which is useful for global key handlers. The listener is added when the view is mounted and removed when the current owner is cleaned up, so it lives as long as the view. These come after any `provide:`s, and modifiers are not supported.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+# fn _a() -> impl IntoView {
+let (last_key, set_last_key) = signal(String::new());
+mview! {
+    on:keydown={move |ev| set_last_key.set(ev.key())};
+    p("last key: " {last_key})
+}
+# }
+```
+
+The handler gets the typed event, the same as `on:` on elements. The listener is added in an effect, so nothing happens when rendering on the server.
+
+## Templates
+
+Repeated parts of a view can be defined once as a template at the start of the macro, with `template name = (...);`, and used anywhere in the macro as a child with `@name`. Templates are defined after `any;`, `provide:`s and global `on:` listeners if they are used.
+
+```
+# use leptos::prelude::*; use leptos_mview::mview;
+mview! {
+    template divider = ( hr.divider; );
+    section {
+        h2("First")
+        @divider
+        h2("Second")
+        @divider
+    }
+}
+# ;
+```
+
+Each template is expanded to a closure (`let divider = || ...;`) that is called wherever it is used, so variables used inside it are captured like any other closure.
+
+## Including templates from files
+
+Large views can be kept in their own files with [`include_mview!`], which reads a file of `mview!` syntax at compile time and expands it like [`mview!`]. The path is relative to the crate root (the directory with `Cargo.toml`), not the current file. Variables in the file are resolved where the macro is called, and the crate is rebuilt when the file changes.
+
+```ignore
+// templates/card.mview contains `div.card { h2({title}) }`
+let title = "Card";
+let card = include_mview!("templates/card.mview");
+```
+
+## Rendering to a string
+
+[`mview_ssr!`] takes the same input as [`mview!`] and is `.to_html()` with compile-time static folding. If the whole view is static (only string literal attributes and children, no directives, blocks or components), the HTML is rendered at compile time and the macro expands to a string literal. Anything else builds the same view as [`mview!`] and calls `.to_html()` on it at runtime, so there is no separate codegen for dynamic views and they render no faster than [`mview!`].
+
+```
+# use leptos::prelude::*; use leptos_mview::mview_ssr;
+let name = "world";
+// rendered at compile time
+let footer = mview_ssr! { footer(p.small("Made with Leptos")) };
+// rendered at runtime
+let greeting = mview_ssr! { h1("Hello " {name} "!") };
+
+assert_eq!(footer, r#"<footer><p class="small">Made with Leptos</p></footer>"#);
+assert_eq!(greeting, "<h1>Hello <!>world<!>!</h1>");
+```
+
 # Extra details
 
 ## Kebab-case identifiers with attribute shorthand
@@ -472,6 +1157,30 @@ If an attribute shorthand has hyphens:
     # ;
     ```
 
+- On `prop:` directives, the property name is used exactly as written, as DOM properties are usually camelCase. Hyphens are only replaced in the variable name.
+    ```
+    # use leptos_mview::mview; use leptos::prelude::*;
+    # #[allow(non_snake_case)] {
+    let innerHTML = "<b>bold</b>";
+    let text_content = "text";
+    mview! { div prop:{innerHTML}; span prop:{text-content}; }
+    # ;
+    // same as...
+    mview! { div prop:innerHTML={innerHTML}; span prop:text-content={text_content}; }
+    # ; }
+    ```
+
+- On `input`, `textarea` and `select` elements, the `{value}` shorthand sets the `value` *property* instead of the attribute, for controlled inputs. Like `prop:value`, the value is not rendered on the server: write `value={value}` to set the attribute instead.
+    ```
+    # use leptos_mview::mview; use leptos::prelude::*;
+    let value = RwSignal::new(String::new());
+    mview! { input type="text" {value} on:input={move |ev| value.set(event_target_value(&ev))}; }
+    # ;
+    // same as...
+    mview! { input type="text" prop:value={value} on:input={move |ev| value.set(event_target_value(&ev))}; }
+    # ;
+    ```
+
 ## Boolean attributes on HTML elements
 
 Note the behaviour from Leptos: setting an HTML attribute to true adds the attribute with no value associated.
@@ -482,6 +1191,12 @@ view! { <input type="checkbox" checked=true data-smth=true not-here=false /> }
 ```
 Becomes `<input type="checkbox" checked data-smth />`, NOT `checked="true"` or `data-smth="true"` or `not-here="false"`.
 
+This also applies to `data-*` attributes: `data-active=false` leaves out `data-active` entirely.
+
+The exceptions are `contenteditable`, `draggable` and `spellcheck`, which take `"true"` and `"false"` as values instead of being boolean attributes. A `true` or `false` literal on these is written as a string, so `div contenteditable=true;` becomes `<div contenteditable="true">` (and `contenteditable` without a value is the same). Any other values are passed through as usual.
+
+As any attribute can be set to a `bool`, there is no list of boolean attributes in the macro. Newer ones like `inert` work the same as `checked` or `disabled`: `div inert;` becomes `<div inert>`.
+
 To have the attribute have a value of the string "true" or "false", use `.to_string()` on the bool. Make sure that it's in a closure if you're working with signals too.
 ```
 # use leptos::prelude::*;
@@ -505,8 +1220,59 @@ Please feel free to make a PR/issue if you have feature ideas/bugs to report/fee
 // Some bits are slightly broken, fix up stray `compile_error`/
 // `ignore`, missing `rust` annotations and remove `#` lines.
 
-pub use leptos_mview_macro::mview;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub use leptos_mview_macro::{include_mview, mview, mview_ssr};
 
 /// Not for public use. Do not implement anything on this.
 #[doc(hidden)]
 pub struct MissingValueAfterEq;
+
+/// Not for public use. Returns a new number for the ids generated by
+/// `#auto(name)`, which is different every time it is called.
+#[doc(hidden)]
+pub fn __next_auto_id() -> usize {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Composes several event handlers into one, which calls each handler in
+/// order with a clone of the event.
+///
+/// Each handler is evaluated once, when the composed handler is created.
+///
+/// # Examples
+///
+/// ```
+/// # use leptos::prelude::*; use leptos_mview::{mview, handlers};
+/// let clicks = RwSignal::new(0);
+/// let log_click = |ev: leptos::ev::MouseEvent| leptos::logging::log!("{ev:?}");
+/// let count = move |_| clicks.update(|n| *n += 1);
+///
+/// mview! {
+///     button on:click={handlers![log_click, count]} ("click")
+/// }
+/// # ;
+/// ```
+#[macro_export]
+macro_rules! handlers {
+    ($($handler:expr),* $(,)?) => {
+        $crate::__handlers!(@bind [] $($handler,)*)
+    };
+}
+
+/// Binds each handler of [`handlers!`] to its own variable, relying on macro
+/// hygiene to keep the variables apart.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __handlers {
+    (@bind [$($bound:ident)*] $first:expr, $($rest:expr,)*) => {{
+        let mut handler = $first;
+        $crate::__handlers!(@bind [$($bound)* handler] $($rest,)*)
+    }};
+    (@bind [$($bound:ident)*]) => {
+        move |ev| {
+            $($bound(::std::clone::Clone::clone(&ev));)*
+        }
+    };
+}
@@ -1,7 +1,7 @@
 use leptos::{prelude::*, task::Executor};
 use leptos_mview::mview;
 mod utils;
-use utils::check_str;
+use utils::{check_str, Contains};
 
 #[test]
 fn basic() {
@@ -42,6 +42,46 @@ fn clones() {
     };
 }
 
+#[test]
+fn clone_into_closure_props() {
+    let name = String::from("mview");
+    let view = mview! {
+        Show
+            clone:name
+            when=[!name.is_empty()]
+            fallback=[name.clone()]
+        {
+            "hi " {name.clone()}
+        }
+    };
+    check_str(view, "hi <!>mview");
+}
+
+#[test]
+fn closure_as_children() {
+    #[component]
+    fn Wrapper(children: Children) -> impl IntoView {
+        mview! { div(children) }
+    }
+    #[component]
+    fn WrapperFn(children: ChildrenFn) -> impl IntoView {
+        mview! { section({children()} {children()}) }
+    }
+
+    let content = || mview! { p("existing") };
+    check_str(
+        mview! { Wrapper children={content}; },
+        "<div><p>existing</p></div>",
+    );
+    check_str(
+        mview! { WrapperFn children={content}; },
+        "<section><p>existing</p><p>existing</p></section>",
+    );
+
+    // a closure in a block child is rendered by calling it
+    check_str(mview! { Wrapper({content}) }, "<div><p>existing</p></div>");
+}
+
 // TODO: not sure why this is creating an untracked resource warning
 #[test]
 fn children_args() {
@@ -64,6 +104,47 @@ fn children_args() {
     };
 }
 
+#[test]
+fn render_prop_children() {
+    use std::rc::Rc;
+
+    // the children are given a function that renders a view
+    #[component]
+    fn Toolbar<F, IV>(children: F) -> impl IntoView
+    where
+        F: Fn(Rc<dyn Fn(&'static str) -> AnyView>) -> IV,
+        IV: IntoView,
+    {
+        let button = Rc::new(|label| mview! { button.tool({label}) }.into_any());
+        mview! { div.toolbar({children(button)}) }
+    }
+
+    #[component]
+    fn Deferred<F, IV>(children: F) -> impl IntoView
+    where
+        F: Fn(fn() -> AnyView) -> IV,
+        IV: IntoView,
+    {
+        children(|| mview! { span("rendered") }.into_any())
+    }
+
+    let r = mview! {
+        Toolbar |button| (
+            {button("save")}
+            {button("open")}
+        )
+    };
+    check_str(
+        r,
+        r#"<div class="toolbar"><button class="tool">save</button><button class="tool">open</button></div>"#,
+    );
+
+    let r = mview! {
+        Deferred |render| { p({render()}) }
+    };
+    check_str(r, "<p><span>rendered</span></p>");
+}
+
 #[test]
 fn generics() {
     use core::marker::PhantomData;
@@ -92,6 +173,31 @@ fn generics() {
     check_str(result, ["alloc::string::String", "usize", "i32"].as_slice());
 }
 
+#[test]
+fn const_generics() {
+    use core::marker::PhantomData;
+    // like type generics, the const needs to be used in a prop so that it can
+    // be inferred by the component body.
+    #[component]
+    pub fn Repeat<const N: usize>(
+        #[prop(optional)] count: PhantomData<[(); N]>,
+        #[prop(into)] text: String,
+    ) -> impl IntoView {
+        let _count = count;
+        text.repeat(N)
+    }
+
+    const TWICE: usize = 2;
+    let result = mview! {
+        Repeat<3> text="a";
+        Repeat::<1> text="b";
+        Repeat<{ TWICE }> text="c";
+        Repeat<{ TWICE * 2 }> text="d";
+    };
+
+    check_str(result, ["aaa", "b", "cc", "dddd"].as_slice());
+}
+
 #[test]
 fn qualified_paths() {
     let _result = mview! {
@@ -107,6 +213,134 @@ fn qualified_paths() {
     // check_str(result, Contains::AllOfNoneOf([&["a"], &["b"]]))
 }
 
+#[test]
+fn animated_show() {
+    let show = RwSignal::new(true);
+    let r = move || {
+        mview! {
+            AnimatedShow
+                when={show}
+                show_class="fade-in"
+                hide_class="fade-out"
+                hide_delay={std::time::Duration::from_millis(300)}
+            {
+                div.toast("Saved")
+            }
+        }
+    };
+    check_str(
+        r(),
+        r#"<div class="fade-in"><div class="toast">Saved</div></div>"#,
+    );
+    show.set(false);
+    check_str(r(), Contains::NoneOf(&["Saved"]));
+}
+
+mod widgets {
+    use leptos::prelude::*;
+    use leptos_mview::mview;
+
+    #[component]
+    pub fn Widget() -> impl IntoView {
+        mview! { span("widget") }
+    }
+
+    pub mod nested {
+        use leptos::prelude::*;
+        use leptos_mview::mview;
+
+        #[component]
+        pub fn Thing() -> impl IntoView {
+            mview! { span("thing") }
+        }
+
+        pub fn view() -> impl IntoView {
+            mview! {
+                crate::widgets::Widget;
+                super::Widget;
+                self::Thing;
+            }
+        }
+    }
+}
+
+#[test]
+fn relative_paths() {
+    check_str(
+        widgets::nested::view(),
+        "<span>widget</span><span>widget</span><span>thing</span>",
+    );
+    check_str(
+        mview! { div { crate::widgets::nested::Thing; self::widgets::Widget; } },
+        "<div><span>thing</span><span>widget</span></div>",
+    );
+}
+
+#[test]
+fn clone_field_path() {
+    #[component]
+    fn Owning(children: ChildrenFn) -> impl IntoView {
+        mview! { div { {children()} } }
+    }
+
+    struct Page {
+        title: String,
+        user: User,
+    }
+
+    struct User {
+        name: String,
+    }
+
+    impl Page {
+        fn view(&self) -> impl IntoView + use<> {
+            mview! {
+                Owning clone:self.title clone:self.user.name {
+                    h1({title.clone()})
+                    p({name.clone()})
+                }
+            }
+        }
+    }
+
+    let page = Page {
+        title: "Home".to_string(),
+        user: User {
+            name: "Ferris".to_string(),
+        },
+    };
+    check_str(page.view(), "<div><h1>Home</h1><p>Ferris</p></div>");
+}
+
+#[test]
+fn with_block_clones() {
+    #[component]
+    fn Owning(children: ChildrenFn) -> impl IntoView {
+        mview! { div { {children()} } }
+    }
+
+    let a = String::from("a");
+    let b = String::from("b");
+    let r = mview! {
+        with { clone: a; clone: b; } (
+            Owning {
+                {a.clone()}
+                section {
+                    Owning { {b.clone()} {a.clone()} }
+                }
+            }
+            Owning { {b.clone()} }
+        )
+        p("after")
+    };
+    check_str(
+        r,
+        "<div>a<section><div>b<!>a</div></section></div><div>b</div><p>after</p>",
+    );
+    // the originals are not moved
+    assert_eq!(a + &b, "ab");
+}
+
 // don't try parse slot:: as a slot
 mod slot {
     use leptos::prelude::*;
@@ -137,6 +371,125 @@ fn let_patterns() {
     }
 }
 
+#[test]
+fn let_binding() {
+    // `For` needs an owner
+    Owner::new().with(|| {
+        let r = mview! {
+            For
+                each=[[1, 2, 3]]
+                key={|i| *i}
+                let:number
+            {
+                span({number})
+            }
+        };
+
+        check_str(r, "<span>1</span><span>2</span><span>3</span>");
+
+        // clone and let together
+        let suffix = String::from("!");
+        let r = mview! {
+            For
+                each=[["a", "b"]]
+                key={|s| *s}
+                clone:suffix
+                let:letter
+            {
+                span({format!("{letter}{suffix}")})
+            }
+        };
+
+        check_str(r, "<span>a!</span><span>b!</span>");
+    });
+}
+
+#[test]
+fn provide_context() {
+    #[derive(Clone)]
+    struct Theme(&'static str);
+
+    #[component]
+    fn ThemedButton() -> impl IntoView {
+        let theme = use_context::<Theme>().map_or("none", |t| t.0);
+        mview! { button class={theme} ("click") }
+    }
+
+    Owner::new().with(|| {
+        let r = mview! {
+            provide: Theme("dark");
+            div { ThemedButton; }
+        };
+        check_str(r, r#"<div><button class="dark">click</button></div>"#);
+    });
+}
+
+// same signatures as the `leptos_meta` components
+mod meta {
+    use leptos::{prelude::*, text_prop::TextProp};
+
+    #[component]
+    pub fn Title(#[prop(optional, into)] text: Option<TextProp>) -> impl IntoView {
+        let text = text.map(|text| text.get());
+        leptos::html::title().child(text)
+    }
+
+    #[component]
+    pub fn Meta(
+        #[prop(optional, into)] name: Option<TextProp>,
+        #[prop(optional, into)] content: Option<TextProp>,
+    ) -> impl IntoView {
+        leptos::html::meta()
+            .name(name.map(|n| n.get()))
+            .content(content.map(|c| c.get()))
+    }
+
+    #[component]
+    pub fn Stylesheet(
+        #[prop(into)] href: String,
+        #[prop(optional, into)] id: Option<String>,
+    ) -> impl IntoView {
+        leptos::html::link().id(id).rel("stylesheet").href(href)
+    }
+
+    #[component]
+    pub fn Link(
+        #[prop(optional, into)] rel: Option<String>,
+        #[prop(optional, into)] href: Option<String>,
+    ) -> impl IntoView {
+        leptos::html::link().rel(rel).href(href)
+    }
+}
+
+#[test]
+fn metadata_components() {
+    let page = RwSignal::new("Home");
+    let r = mview! {
+        meta::Title text="Static title";
+        meta::Title text=[format!("{} | Site", page.get())];
+        meta::Meta name="description" content="A page";
+    };
+    check_str(
+        r,
+        r#"<title>Static title</title><title>Home | Site</title><meta name="description" content="A page">"#,
+    );
+
+    let theme = "dark";
+    let r = mview! {
+        meta::Stylesheet id="main" href="/pkg/app.css";
+        meta::Stylesheet href={format!("/themes/{theme}.css")};
+        meta::Link rel="icon" href="/favicon.ico";
+    };
+    check_str(
+        r,
+        r#"<link id="main" rel="stylesheet" href="/pkg/app.css"><link rel="stylesheet" href="/themes/dark.css"><link rel="icon" href="/favicon.ico">"#,
+    );
+
+    // lowercase `title` is still the HTML element
+    let r = mview! { title("plain") };
+    check_str(r, "<title>plain</title>");
+}
+
 #[component]
 fn TakesClass() -> impl IntoView {
     mview! {
@@ -176,6 +529,18 @@ fn class_dir() {
     );
 }
 
+#[test]
+fn style_dir() {
+    let color = RwSignal::new("red");
+    let r = mview! {
+        TakesClass class:active=[true] style:color=[color.get()] style:--gap="1px";
+    };
+    check_str(
+        r,
+        r#"<div class="takes-class active" style="color:red;--gap:1px;">"#,
+    );
+}
+
 #[test]
 fn ids() {
     let r = mview! {
@@ -184,3 +549,238 @@ fn ids() {
 
     check_str(r, r#"<div id="id-1 id-number-two" class="i-take-ids""#)
 }
+
+#[test]
+fn mixed_selectors() {
+    let r = mview! {
+        TakesClass.extra-class #my-id .another;
+    };
+
+    check_str(
+        r,
+        r#"<div id="my-id" class="takes-class extra-class another">I take more classes!</div>"#,
+    )
+}
+
+#[test]
+fn dynamic_attr_name() {
+    let name = "data-dynamic";
+    let r = mview! {
+        TakesIds attr:{name}="yes";
+    };
+
+    check_str(r, r#"<div data-dynamic="yes" class="i-take-ids">"#)
+}
+
+#[test]
+fn children_shorthand() {
+    #[component]
+    fn Layout(children: Children) -> impl IntoView {
+        mview! {
+            main.layout {
+                h1("title")
+                children
+            }
+        }
+    }
+
+    let r = mview! {
+        Layout {
+            p("content")
+        }
+    };
+
+    check_str(
+        r,
+        r#"<main class="layout"><h1>title</h1><p>content</p></main>"#,
+    );
+}
+
+#[test]
+fn aliased_shorthand() {
+    #[component]
+    fn Header(title: String, level: u8) -> impl IntoView {
+        mview! { header(h1(f["{title} ({level})"])) }
+    }
+
+    let page_title = String::from("Home");
+    let depth = 2_u32;
+    let r = mview! {
+        Header {page_title as title} {depth as u8 as level};
+    };
+    check_str(r, "<header><h1>Home (2)</h1></header>");
+
+    let label = "Search";
+    let r = mview! { input {label as aria-label} {label.to_lowercase() as name}; };
+    check_str(r, r#"<input aria-label="Search" name="search">"#);
+}
+
+#[test]
+fn view_props() {
+    #[component]
+    fn Card(
+        view: ViewFn,
+        #[prop(optional, into)] footer: ViewFn,
+        children: Children,
+    ) -> impl IntoView {
+        mview! {
+            article {
+                header({view.run()})
+                children
+                footer({footer.run()})
+            }
+        }
+    }
+
+    let title = String::from("Title");
+    let r = mview! {
+        Card {
+            view: ( h2({title.clone()}) )
+            p("body")
+            footer: { "a" strong("b") }
+        }
+    };
+    check_str(
+        r,
+        "<article><header><h2>Title</h2></header><p>body</p><footer>a<strong>b</strong></footer></article>",
+    );
+
+    // optional view props can be left out
+    let r = mview! {
+        Card(view: ("title") "body")
+    };
+    check_str(r, "<article><header>title</header>body<footer>");
+}
+
+#[test]
+fn single_block_child() {
+    #[component]
+    fn Wrapper(children: Children) -> impl IntoView {
+        mview! { div.wrapper(children) }
+    }
+
+    let count = 2;
+    // parens are always children, not positional props
+    let parens = mview! {
+        Wrapper({count + 1})
+    };
+    let braces = mview! {
+        Wrapper { {count + 1} }
+    };
+
+    check_str(parens, r#"<div class="wrapper">3</div>"#);
+    check_str(braces, r#"<div class="wrapper">3</div>"#);
+}
+
+#[test]
+fn passive_event_on_component() {
+    let r = mview! {
+        TakesIds on:wheel:passive={|ev: leptos::ev::WheelEvent| _ = ev.delta_y()};
+    };
+    check_str(r, r#"<div class="i-take-ids">"#)
+}
+
+#[test]
+fn struct_attribute() {
+    #[derive(Default)]
+    struct ChartConfig {
+        width: u32,
+        height: u32,
+        title: &'static str,
+        animated: bool,
+    }
+
+    #[component]
+    fn Chart(config: ChartConfig, #[prop(into)] label: String) -> impl IntoView {
+        mview! {
+            figure data-animated={config.animated} (
+                figcaption({label} ": " {config.title})
+                f["{}x{}", config.width, config.height]
+            )
+        }
+    }
+
+    let height = 200;
+    let r = mview! {
+        Chart config:{ width=300 {height} title="sales" animated } label="chart";
+    };
+    check_str(
+        r,
+        "<figure data-animated><figcaption>chart<!>: <!>sales</figcaption>300x200</figure>",
+    );
+
+    // same as...
+    let r = mview! {
+        Chart config={ChartConfig { width: 300, height, animated: true, ..Default::default() }} label="chart";
+    };
+    check_str(r, "300x200");
+
+    // the generated helper does not shadow user items
+    fn build() -> u32 { 50 }
+    let r = mview! { Chart config:{ width={build()} } label="chart"; };
+    check_str(r, "50x0");
+}
+
+#[server]
+async fn add_todo(title: String) -> Result<(), ServerFnError> {
+    _ = title;
+    Ok(())
+}
+
+#[test]
+fn forms() {
+    // the action is passed in like any other prop
+    let add_todo = ServerAction::<AddTodo>::new();
+    let r = mview! {
+        ActionForm action={add_todo} (
+            input type="text" name="title";
+            button type="submit" ("Add")
+        )
+    };
+    check_str(
+        r,
+        r#"method="post"><input type="text" name="title"><button type="submit">Add</button></form>"#,
+    );
+
+    // shorthand works too
+    let action = ServerAction::<AddTodo>::new();
+    check_str(mview! { ActionForm {action} ("Add") }, "Add</form>");
+
+    let r = mview! {
+        leptos_router::components::Form action="/search" (
+            input name="q";
+        )
+    };
+    check_str(
+        r,
+        r#"<form method="get" action="/search"><input name="q"></form>"#,
+    );
+}
+
+#[component]
+fn Splitter(children: Vec<AnyView>) -> impl IntoView {
+    let count = children.len();
+    let panes = children
+        .into_iter()
+        .map(|pane| mview! { div.pane({pane}) })
+        .collect_view();
+    mview! { div.splitter data-panes={count} ({panes}) }
+}
+
+#[test]
+fn collected_children() {
+    let name = String::from("right");
+    let r = mview! {
+        Splitter collect:children clone:name (
+            "left"
+            {name.clone()}
+        )
+    };
+    check_str(
+        r,
+        r#"data-panes="2" class="splitter"><div class="pane">left</div><div class="pane">right</div>"#,
+    );
+
+    // the `Vec` is still passed if there are no children
+    check_str(mview! { Splitter collect:children; }, r#"data-panes="0""#);
+}
@@ -0,0 +1,423 @@
+//! Tests for the special keyword constructs that can be used as children.
+
+use std::{error::Error, fmt};
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_mview::mview;
+mod utils;
+use utils::{check_str, Contains};
+
+#[derive(Debug)]
+struct Failed;
+
+impl fmt::Display for Failed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("failed") }
+}
+
+impl Error for Failed {}
+
+#[test]
+fn err_boundary_ok() {
+    let ok: Result<&str, Failed> = Ok("all good");
+    let r = mview! {
+        div {
+            err_boundary fallback=[mview! { p("error!") }] (
+                span({ok})
+            )
+        }
+    };
+
+    check_str(
+        r,
+        Contains::AllOfNoneOf([["<span>all good</span>"].as_slice(), ["error!"].as_slice()]),
+    );
+}
+
+#[test]
+fn err_boundary_err() {
+    let err: Result<&str, Failed> = Err(Failed);
+    let r = mview! {
+        div {
+            err_boundary fallback=[mview! { p("error!") }] (
+                span({err})
+            )
+        }
+    };
+
+    check_str(r, "<p>error!</p>");
+}
+
+#[test]
+fn err_boundary_block_fallback() {
+    let err: Result<&str, Failed> = Err(Failed);
+    let r = mview! {
+        err_boundary
+            fallback={|errors: ArcRwSignal<leptos::error::Errors>| {
+                let count = errors.get_untracked().into_iter().count();
+                mview! { p(f["{count} error(s)"]) }
+            }}
+        (
+            {err}
+        )
+    };
+
+    check_str(r, "1 error(s)");
+}
+
+#[test]
+fn raw_text() {
+    let escaped = "Fish &amp; chips &lt;3";
+    let r = mview! {
+        p {
+            "Menu: "
+            raw_text({escaped})
+            raw_text("&copy; 2024")
+        }
+    };
+
+    check_str(
+        r,
+        "<p>Menu: <span>Fish &amp; chips &lt;3</span><span>&copy; 2024</span></p>",
+    );
+}
+
+#[test]
+fn for_loop() {
+    let fruits = vec!["apple", "banana"];
+    let r = mview! {
+        ul {
+            for {fruit} in {fruits} (
+                li({fruit})
+            )
+        }
+    };
+
+    check_str(r, "<ul><li>apple</li><li>banana</li><!></ul>");
+}
+
+#[test]
+fn for_loop_else() {
+    let list = |items: Vec<&'static str>| {
+        mview! {
+            ul {
+                for {(i, item)} in {items.into_iter().enumerate()} {
+                    li(f["{i}: {item}"])
+                } else {
+                    li.empty("No items")
+                }
+            }
+        }
+    };
+
+    check_str(
+        list(vec!["a", "b"]),
+        Contains::AllOfNoneOf([
+            ["<li>0: a</li>", "<li>1: b</li>"].as_slice(),
+            ["No items"].as_slice(),
+        ]),
+    );
+    check_str(list(vec![]), r#"<ul><li class="empty">No items</li></ul>"#);
+}
+
+#[test]
+fn match_arms() {
+    #[derive(Clone, Copy)]
+    enum State {
+        Idle,
+        Loading(u8),
+        Done { items: usize },
+    }
+
+    let view = |state: State| {
+        mview! {
+            div {
+                match {state} {
+                    State::Idle => ( "Idle" )
+                    State::Loading(percent) if percent < 50 => (
+                        progress max="100" value={percent};
+                        span.hint("Just started")
+                    ),
+                    State::Loading(percent) => ( progress max="100" value={percent}; ),
+                    State::Done { items } => {
+                        h2("Done")
+                        p(f["{items} items"])
+                        button("Again")
+                    }
+                }
+            }
+        }
+    };
+    check_str(view(State::Idle), "<div>Idle</div>");
+    check_str(
+        view(State::Loading(10)),
+        r#"<div><progress max="100" value="10"></progress><span class="hint">Just started</span></div>"#,
+    );
+    check_str(
+        view(State::Loading(80)),
+        r#"<div><progress max="100" value="80"></progress></div>"#,
+    );
+    check_str(
+        view(State::Done { items: 3 }),
+        "<div><h2>Done</h2><p>3 items</p><button>Again</button></div>",
+    );
+
+    // rerenders when the bracket changes
+    let state = RwSignal::new(State::Idle);
+    let r = move || mview! { p(match [state.get()] { State::Idle => ("idle") _ => ("busy" strong("!")) }) };
+    check_str(r(), "<p>idle");
+    state.set(State::Loading(1));
+    check_str(r(), "<p>busy<strong>!</strong>");
+}
+
+#[test]
+fn rust_match_body() {
+    let view = |count: Option<usize>| {
+        mview! {
+            match count {
+                Some(0) => mview! { p("none") }.into_any(),
+                Some(n) => mview! { ul { each({(0..n).map(|i| mview! { li({i}) })}) } }.into_any(),
+                None => "loading".into_any(),
+            }
+        }
+    };
+    check_str(view(Some(0)), "<p>none</p>");
+    check_str(view(Some(2)), "<ul><li>0</li><li>1</li><!></ul>");
+    check_str(view(None), "loading");
+}
+
+#[test]
+fn rust_if_body() {
+    let view = |count: usize| {
+        mview! {
+            if count == 0 {
+                mview! { p("none") }.into_any()
+            } else if count == 1 {
+                "one".into_any()
+            } else {
+                mview! { strong({count}) }.into_any()
+            }
+        }
+    };
+    check_str(view(0), "<p>none</p>");
+    check_str(view(1), "one");
+    check_str(view(3), "<strong>3</strong>");
+
+    // followed by other children, this is an inline `if`
+    let r = mview! { if true { "a" } else { "b" } span("c") };
+    check_str(r, "a<span>c</span>");
+}
+
+#[test]
+fn for_loop_reactive() {
+    let items = RwSignal::new(vec![1, 2]);
+    let r = move || {
+        mview! {
+            p {
+                for {n} in [items.get()] (
+                    span({n})
+                ) else (
+                    "none"
+                )
+            }
+        }
+    };
+
+    check_str(r(), "<p><span>1</span><span>2</span><!></p>");
+    items.set(vec![]);
+    check_str(r(), "<p>none</p>");
+}
+
+#[test]
+fn for_loop_keyed() {
+    #[derive(Clone)]
+    struct Todo {
+        id: u32,
+        name: &'static str,
+    }
+
+    // `For` needs an owner
+    Owner::new().with(|| {
+        let todos = RwSignal::new(vec![Todo { id: 1, name: "a" }, Todo { id: 2, name: "b" }]);
+
+        // a signal is read with `.get()`
+        let r = mview! {
+            ul {
+                for {todo} in {todos} key={|todo| todo.id} (
+                    li({todo.name})
+                )
+            }
+        };
+        todos.update(|todos| todos.push(Todo { id: 3, name: "c" }));
+        check_str(r, "<ul><li>a</li><li>b</li><li>c</li><!></ul>");
+
+        // a bracket is used as the `each` closure
+        let r = mview! {
+            ul {
+                for {todo} in [todos.get().into_iter().rev()] key={|todo| todo.id} (
+                    li({todo.name})
+                )
+            }
+        };
+        todos.update(|todos| todos.retain(|todo| todo.id != 2));
+        check_str(r, "<ul><li>c</li><li>a</li><!></ul>");
+    });
+}
+
+#[test]
+fn templates() {
+    let label = "item";
+    let r = mview! {
+        template entry = ( li.entry({label}) );
+        ul {
+            @entry
+            li("middle")
+            @entry
+        }
+    };
+
+    check_str(
+        r,
+        r#"<ul><li class="entry">item</li><li>middle</li><li class="entry">item</li></ul>"#,
+    );
+
+    // works together with `any;`
+    let r: AnyView = mview! {
+        any;
+        template bold = ( strong("!") );
+        p("hi" @bold)
+    };
+    check_str(r, "<p>hi<strong>!</strong></p>");
+}
+
+#[test]
+fn included_template() {
+    // variables are resolved where the macro is called
+    let title = "Card";
+    check_str(
+        leptos_mview::include_mview!("tests/templates/card.mview"),
+        r#"<div class="card"><h2>Card</h2><p>Included from a file</p></div>"#,
+    );
+}
+
+#[test]
+fn resource() {
+    _ = any_spawner::Executor::init_futures_executor();
+    let owner = Owner::new();
+    owner.set();
+    let count = Resource::new(|| (), |_| async { 3 });
+    let view = move || {
+        mview! {
+            div {
+                resource {count} loading=["Loading..."] |n| (
+                    p(f["{n} little monkeys"])
+                )
+            }
+        }
+    };
+
+    // synchronous rendering only shows the fallback
+    check_str(view(), "<div>Loading...</div>");
+
+    let html = futures::executor::block_on(view().to_html_stream_in_order().collect::<String>());
+    assert_eq!(html, "<div><p>3 little monkeys</p></div>");
+}
+
+#[test]
+fn each_iterator() {
+    let r = mview! {
+        ul {
+            each({(0..3).map(|i| mview! { li({i}) })})
+        }
+    };
+    check_str(r, "<ul><li>0</li><li>1</li><li>2</li><!></ul>");
+
+    let len = RwSignal::new(2);
+    let r = move || {
+        mview! {
+            p(each([(0..len.get()).map(|i| mview! { span({i}) })]))
+        }
+    };
+    check_str(r(), "<p><span>0</span><span>1</span><!></p>");
+    len.set(1);
+    check_str(r(), "<p><span>0</span><!></p>");
+}
+
+#[test]
+fn each_optional_iterator() {
+    let render = |items: Option<Vec<&'static str>>| {
+        mview! {
+            ul {
+                each?({items.map(|items| items.into_iter().map(|i| mview! { li({i}) }))})
+            }
+        }
+    };
+    check_str(
+        render(Some(vec!["a", "b"])),
+        "<ul><li>a</li><li>b</li><!></ul>",
+    );
+    check_str(render(None), "<ul><!></ul>");
+
+    let items = RwSignal::new(None::<Vec<u32>>);
+    let r = move || {
+        mview! {
+            p(each?([items.get().map(|v| v.into_iter().map(|i| mview! { span({i}) }))]))
+        }
+    };
+    check_str(r(), "<p><!></p>");
+    items.set(Some(vec![1, 2]));
+    check_str(r(), "<p><span>1</span><span>2</span><!></p>");
+}
+
+#[test]
+fn live_region() {
+    let message = RwSignal::new("Saved");
+    check_str(
+        mview! { live_region(polite) ( [message.get()] ) },
+        r#"<div aria-live="polite" aria-atomic="true">Saved</div>"#,
+    );
+
+    // selectors and other attributes are passed on to the div
+    check_str(
+        mview! { live_region(assertive).toast role="alert" ("Error") },
+        r#"<div role="alert" aria-live="assertive" aria-atomic="true" class="toast">Error</div>"#,
+    );
+}
+
+#[test]
+fn show_hidden() {
+    #[component]
+    fn Details() -> impl IntoView {
+        mview! { p("details") }
+    }
+
+    let open = RwSignal::new(false);
+    let r = move || {
+        mview! {
+            div {
+                show_hidden [open.get()] (
+                    input type="text";
+                    Details;
+                )
+                span("always")
+            }
+        }
+    };
+    // the children are still rendered, only hidden
+    check_str(
+        r(),
+        r#"<div><input type="text" hidden><p hidden>details</p><span>always</span></div>"#,
+    );
+    open.set(true);
+    check_str(
+        r(),
+        r#"<div><input type="text"><p>details</p><span>always</span></div>"#,
+    );
+
+    // a block condition is called, like `Show`'s `when`
+    let is_open = move || open.get();
+    check_str(
+        mview! { show_hidden {is_open} { textarea("draft") } },
+        "<textarea>draft</textarea>",
+    );
+}
@@ -29,6 +29,26 @@ fn single_element() {
     check_str(result, r#"<div>hi</div>"#);
 }
 
+#[test]
+fn typed_element_output() {
+    // a single element keeps its concrete type, so more attributes can be added
+    fn card(
+        title: &'static str,
+    ) -> HtmlElement<html::Div, impl leptos::attr::Attribute, impl RenderHtml> {
+        mview! { div.card(h2({title})) }
+    }
+
+    let node_ref = NodeRef::<html::Div>::new();
+    let r = card("hi")
+        .id("first")
+        .attr("data-index", "1")
+        .node_ref(node_ref);
+    check_str(
+        r,
+        r#"<div id="first" data-index="1" class="card"><h2>hi</h2></div>"#,
+    );
+}
+
 #[test]
 fn multi_element_is_fragment() {
     let _fragment: View<_> = mview! {
@@ -37,6 +57,19 @@ fn multi_element_is_fragment() {
     };
 }
 
+#[test]
+fn into_any_view() {
+    let views: Vec<AnyView> = vec![
+        mview! { any; p("one") },
+        mview! { any; span("two") strong("three") },
+        mview! { any; },
+    ];
+    check_str(
+        views.collect_view(),
+        "<p>one</p><span>two</span><strong>three</strong>",
+    );
+}
+
 #[test]
 fn a_bunch() {
     let result = mview! {
@@ -78,6 +111,19 @@ fn directive_before_attr() {
     check_str(result, "font-size: 1em;;color:black;");
 }
 
+#[test]
+fn style_merging() {
+    let width = RwSignal::new(10);
+    let r = move || {
+        mview! {
+            div style:width=[format!("{}px", width.get())] style="color: red";
+        }
+    };
+    check_str(r(), r#"<div style="color: red;width:10px;"></div>"#);
+    width.set(20);
+    check_str(r(), r#"<div style="color: red;width:20px;"></div>"#);
+}
+
 #[test]
 fn multiple_directives() {
     let yes = move || true;
@@ -100,6 +146,17 @@ fn multiple_directives() {
     );
 }
 
+#[test]
+fn numeric_literal_values() {
+    let result = mview! {
+        div data-count=1_000 tabindex=-1 data-offset=-2.5 data-byte=255u8;
+    };
+    check_str(
+        result,
+        r#"<div data-count="1000" tabindex="-1" data-offset="-2.5" data-byte="255"></div>"#,
+    );
+}
+
 #[test]
 fn string_directives() {
     let yes = move || true;
@@ -116,6 +173,127 @@ fn string_directives() {
     )
 }
 
+#[test]
+fn enumerated_attributes() {
+    // checked with the `strict` feature, values are case-insensitive
+    let r = mview! {
+        img src="/a.png" loading="lazy" decoding="ASYNC" fetchpriority="low";
+    };
+    check_str(
+        r,
+        r#"<img src="/a.png" loading="lazy" decoding="ASYNC" fetchpriority="low">"#,
+    );
+
+    // only literals are checked
+    let loading = "eager";
+    let r = mview! {
+        p dir="rtl" {
+            img {loading} crossorigin="";
+        }
+    };
+    check_str(
+        r,
+        r#"<p dir="rtl"><img loading="eager" crossorigin=""></p>"#,
+    );
+}
+
+#[test]
+fn computed_directive_names() {
+    const PRIMARY: &str = concat!("btn", "--", "primary");
+    let active = RwSignal::new(true);
+    let result = mview! {
+        button.btn
+            class:{concat!("btn-", "primary")}=[active.get()]
+            class:{PRIMARY}=true
+            class:{"btn-inactive"}=[!active.get()]
+            style:{concat!("--", "gap")}="1px"
+        ("click")
+    };
+
+    check_str(
+        result,
+        r#"<button class="btn-primary btn--primary  btn" style="--gap:1px;">click</button>"#,
+    )
+}
+
+#[test]
+fn multiple_class_names() {
+    const NAMES: [&str; 3] = ["btn", "btn-large", "btn-primary"];
+    let active = RwSignal::new(true);
+    let r = move || {
+        mview! {
+            button class:{..NAMES}=[active.get()] class:{..["x", "y"]}=true ("click")
+        }
+    };
+    check_str(
+        r(),
+        r#"<button class="btn btn-large btn-primary x y">click</button>"#,
+    );
+
+    active.set(false);
+    check_str(r(), r#"<button class="x y">click</button>"#);
+}
+
+#[test]
+fn optional_use_directive() {
+    fn tooltip(_el: leptos::tachys::renderer::types::Element, _text: String) {}
+
+    // directives only run in the browser, this just checks that both `Some` and
+    // `None` are accepted and the element is still rendered.
+    for text in [Some("hi"), None] {
+        let result = mview! {
+            button use:tooltip=?{text} { "hover" }
+        };
+        check_str(result, "<button>hover</button>");
+    }
+}
+
+#[test]
+fn optional_style_directive() {
+    let display = RwSignal::new(Some("none"));
+    let view = || {
+        mview! {
+            div style:display=?[display.get()] style:color="red";
+        }
+    };
+    check_str(view(), r#"<div style="display:none;color:red;"></div>"#);
+
+    display.set(None);
+    check_str(view(), r#"<div style="color:red;"></div>"#);
+}
+
+#[test]
+fn optional_prop_directive() {
+    // properties are set on the DOM node, so they are not in the HTML. this
+    // only checks that an `Option` is accepted.
+    let value = RwSignal::new(Some(String::from("typed")));
+    let view = || {
+        mview! {
+            input type="text" prop:value=?[value.get()];
+        }
+    };
+    check_str(view(), r#"<input type="text">"#);
+
+    value.set(None);
+    check_str(view(), r#"<input type="text">"#);
+}
+
+#[test]
+fn css_custom_properties() {
+    let theme = RwSignal::new("rebeccapurple");
+    let result = mview! {
+        div
+            style:--theme-color=[theme.get()]
+            style:--gap-2="4px"
+            style:color="var(--theme-color)";
+    };
+
+    check_str(
+        result,
+        r#"style="--theme-color:rebeccapurple;--gap-2:4px;color:var(--theme-color);""#,
+    )
+}
+
 #[test]
 fn mixed_class_creation() {
     let class: TextProp = "some-class another-class".into();
@@ -126,6 +304,30 @@ fn mixed_class_creation() {
     check_str(r, r#"class="some-class another-class always-here""#);
 }
 
+#[test]
+fn merged_static_classes() {
+    // selectors and `class="..."` are merged into one `.class("...")` call
+    let r = mview! { div.a.b class="c d"; };
+    let expected = leptos::html::div().class("c d a b");
+    assert_eq!(
+        std::any::type_name_of_val(&r),
+        std::any::type_name_of_val(&expected)
+    );
+    check_str(r, r#"<div class="c d a b"></div>"#);
+
+    // dynamic classes still work alongside
+    let active = RwSignal::new(true);
+    let extra = "e";
+    let r = mview! {
+        span.a.b class="c" class:active=[active.get()] class:{extra}=true;
+    };
+    check_str(r, r#"<span class="c a b active e"></span>"#);
+
+    // nothing is merged with dynamic `class` attributes
+    let r = mview! { p.a.b class=[extra]; };
+    check_str(r, r#"<p class="e a b"></p>"#);
+}
+
 #[test]
 fn custom_web_component() {
     let component = mview! {
@@ -140,6 +342,55 @@ fn custom_web_component() {
     );
 }
 
+#[test]
+fn web_component_attributes() {
+    let value = RwSignal::new(3);
+    let r = mview! {
+        my-widget.card #main data-size="lg" prop:value={value} class:active=true on:click={|_| ()} {
+            my-widget-item("static")
+        }
+    };
+
+    // properties aren't rendered in SSR
+    check_str(
+        r,
+        r#"<my-widget data-size="lg" id="main" class="active card"><my-widget-item>static</my-widget-item></my-widget>"#,
+    );
+}
+
+#[test]
+fn shadow_dom_attributes() {
+    let slot = "extra";
+    let r = mview! {
+        my-dialog exportparts="title, body" {
+            span part="title" slot="header" ("Title")
+            my-button part="action" slot="footer";
+            div {slot} ("x")
+        }
+    };
+
+    // `slot=...` is an attribute, only `slot:` is a Leptos slot
+    check_str(
+        r,
+        r#"<my-dialog exportparts="title, body"><span part="title" slot="header">Title</span><my-button part="action" slot="footer"></my-button><div slot="extra">x</div></my-dialog>"#,
+    );
+}
+
+#[test]
+fn keyword_prefixed_custom_elements() {
+    // these are custom elements, not the `children`, `space` or `nbsp` keywords
+    let r = mview! {
+        children-list(li("a"))
+        space-bar("b")
+        nbsp-x;
+    };
+
+    check_str(
+        r,
+        "<children-list><li>a</li></children-list><space-bar>b</space-bar><nbsp-x></nbsp-x>",
+    );
+}
+
 #[test]
 fn has_ref() {
     let node_ref = NodeRef::new();
@@ -174,3 +425,645 @@ fn doctype() {
 
     check_str(doctype, "<!DOCTYPE html><div></div>");
 }
+
+#[test]
+fn event_value_modifier() {
+    let (value, set_value) = signal(String::new());
+    let r = mview! {
+        input
+            type="text"
+            prop:{value}
+            on:input:value={move |v: String| set_value.set(v)};
+    };
+    check_str(r, r#"<input type="text">"#);
+
+    // works with a handler that isn't a closure literal too
+    let set_upper = move |v: String| set_value.set(v.to_uppercase());
+    _ = mview! {
+        textarea on:change:value={set_upper};
+    };
+}
+
+#[test]
+fn event_set_modifier() {
+    let (value, set_value) = signal(String::new());
+    let r = mview! {
+        input type="text" prop:{value} on:input:set={set_value};
+    };
+    check_str(r, r#"<input type="text">"#);
+
+    // any `Set` works, and the value is converted with `.into()`
+    let optional = RwSignal::new(None::<String>);
+    let shared = RwSignal::new(std::sync::Arc::<str>::from(""));
+    _ = mview! {
+        textarea on:change:set={optional};
+        select on:change:set={shared};
+    };
+}
+
+#[test]
+fn event_tuple_handler() {
+    let (value, set_value) = signal(String::new());
+    let r = mview! {
+        input type="text" prop:{value} on:input=(event_target_value, set_value);
+    };
+    check_str(r, r#"<input type="text">"#);
+
+    // any extractor that takes the event, and the value is converted with `.into()`
+    let checked = RwSignal::new(false);
+    let length = RwSignal::new(None::<usize>);
+    _ = mview! {
+        input type="checkbox" on:change=(event_target_checked, checked);
+        textarea on:input=(|ev: &leptos::ev::Event| event_target_value(ev).len(), length,);
+    };
+}
+
+#[test]
+fn attribute_order() {
+    // attributes are rendered in source order, with `class` and `style`
+    // (merged from all sources) always at the end. Static classes are merged
+    // before any `class:` directives.
+    let r = mview! {
+        a.sel #the-id
+            href="#"
+            class="c"
+            target="_blank"
+            style="color: red"
+            rel="noopener"
+            data-x=1
+            aria-label="l"
+            title="t"
+            class:dir=true
+            style:width="1px";
+    };
+
+    check_str(
+        r,
+        r##"<a href="#" target="_blank" rel="noopener" data-x="1" aria-label="l" title="t" id="the-id" class="c sel dir" style="color: red;width:1px;"></a>"##,
+    );
+
+    // swapping attributes swaps the output
+    let r = mview! {
+        a title="t" href="#" data-x=1;
+    };
+    check_str(r, r##"<a title="t" href="#" data-x="1"></a>"##);
+}
+
+#[test]
+fn bind_number() {
+    let float = RwSignal::new(1.5_f64);
+    let (int, set_int) = signal(3_i32);
+
+    let r = mview! {
+        input type="number" bind:number={float};
+        input type="number" bind:number={(int, set_int)};
+    };
+
+    // properties aren't rendered in SSR
+    check_str(r, r#"<input type="number"><input type="number">"#);
+    assert_eq!(float.get_untracked(), 1.5);
+    assert_eq!(int.get_untracked(), 3);
+}
+
+#[test]
+fn event_prevent_if_modifier() {
+    let submitting = RwSignal::new(false);
+    let r = mview! {
+        form on:submit:prevent_if={move |_ev| submitting.get()} {
+            button type="submit" ("submit")
+        }
+    };
+    check_str(r, r#"<form><button type="submit">submit</button></form>"#);
+}
+
+#[test]
+fn event_log_modifier() {
+    let clicks = RwSignal::new(0);
+    let mut fired = Vec::new();
+    let r = mview! {
+        button
+            on:click:log={move |_| clicks.update(|n| *n += 1)}
+            // `FnMut` handlers still work
+            on:dblclick:log={move |_ev: leptos::ev::MouseEvent| fired.push("dblclick")}
+        ("log")
+    };
+    // logging doesn't change the rendered element
+    check_str(r, "<button>log</button>");
+}
+
+#[test]
+fn event_target_modifier() {
+    use leptos::web_sys::HtmlInputElement;
+
+    let value = RwSignal::new(String::new());
+    let input = move |el: HtmlInputElement, _ev| value.set(el.value());
+    let r = mview! {
+        input
+            type="text"
+            on:change:target::<HtmlInputElement>={move |el, _ev| value.set(el.value())}
+            on:{input}:target::<HtmlInputElement>;
+    };
+    check_str(r, r#"<input type="text">"#);
+}
+
+#[test]
+fn composed_handlers() {
+    use std::{cell::RefCell, rc::Rc};
+
+    use leptos_mview::handlers;
+
+    // events can't be fired in tests, so call the composed handler directly
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let log = |name: &'static str| {
+        let calls = Rc::clone(&calls);
+        move |ev: u8| calls.borrow_mut().push((name, ev))
+    };
+    let handler = handlers![log("log_click"), log("track"), log("do_thing")];
+    handler(1);
+    assert_eq!(*calls.borrow(), [
+        ("log_click", 1),
+        ("track", 1),
+        ("do_thing", 1)
+    ]);
+
+    let clicks = RwSignal::new(0);
+    let count = move |_| clicks.update(|n| *n += 1);
+    let r = mview! {
+        button on:click={handlers![count, |ev: leptos::ev::MouseEvent| drop(ev)]} ("click")
+    };
+    check_str(r, "<button>click</button>");
+}
+
+#[test]
+fn event_self_modifier() {
+    let open = RwSignal::new(true);
+    let r = mview! {
+        div.backdrop on:click:self={move |_| open.set(false)} {
+            div.modal on:click:self={move |ev: leptos::ev::MouseEvent| drop(ev)} ("content")
+        }
+    };
+    check_str(
+        r,
+        r#"<div class="backdrop"><div class="modal">content</div></div>"#,
+    );
+}
+
+#[test]
+fn event_spa_modifier() {
+    // events can't be fired in tests, this checks that the handler takes the
+    // href as a string
+    let visited = RwSignal::new(Vec::<String>::new());
+    let r = mview! {
+        nav {
+            a href="/about" on:click:spa={move |href: String| visited.update(|v| v.push(href))} ("About")
+            a href="/blog" on:click:spa={move |href| visited.update(|v| v.push(href))} ("Blog")
+        }
+    };
+    check_str(
+        r,
+        r#"<nav><a href="/about">About</a><a href="/blog">Blog</a></nav>"#,
+    );
+}
+
+#[test]
+fn event_key_modifiers() {
+    // events can't be fired in tests, this checks that the handlers are typed
+    // as keyboard events
+    let submitted = RwSignal::new(false);
+    let r = mview! {
+        input
+            on:keydown:enter={move |_| submitted.set(true)}
+            on:keyup:escape={move |ev: leptos::ev::KeyboardEvent| drop(ev)}
+            on:keydown:up={|_| ()}
+            on:keydown:space={|_| ()};
+    };
+    check_str(r, "<input>");
+}
+
+#[cfg(feature = "inert-html")]
+#[test]
+fn inert_static_elements() {
+    let r = mview! {
+        footer {
+            nav.links #main-nav aria-label="footer" {
+                a href="/about" ("About" " us")
+                hr;
+                p title="a \"quote\"" ("1 < 2 & 3")
+            }
+        }
+    };
+
+    // adjacent text is not separated by a marker, as the `nav` is rendered to
+    // a single string at compile time.
+    check_str(
+        r,
+        r#"<footer><nav aria-label="footer" id="main-nav" class="links"><a href="/about">About us</a><hr><p title="a &quot;quote&quot;">1 &lt; 2 &amp; 3</p></nav></footer>"#,
+    );
+}
+
+#[test]
+fn dynamic_attr_name() {
+    let name = String::from("data-from-config");
+    let r = mview! {
+        div {
+            span attr:{name.clone()}="value" attr:{"aria-hidden"}=[true] ("hi")
+        }
+    };
+    check_str(r, r#"<span data-from-config="value" aria-hidden>hi</span>"#);
+}
+
+#[test]
+fn computed_attr_name() {
+    #[derive(Clone, Copy)]
+    enum Size {
+        Large,
+        Small,
+    }
+
+    let big = true;
+    for (size, expected) in [
+        (Size::Large, r#"<div data-big="yes" data-lg="1"></div>"#),
+        (Size::Small, r#"<div data-big="yes" data-sm="1"></div>"#),
+    ] {
+        let r = mview! {
+            div
+                attr:{if big { "data-big" } else { "data-small" }}="yes"
+                attr:{match size {
+                    Size::Large => "data-lg",
+                    Size::Small => "data-sm",
+                }}=1;
+        };
+        check_str(r, expected);
+    }
+}
+
+#[test]
+fn literal_attr_names() {
+    let r = mview! {
+        div attr:"on:x"="y" attr:"x-on:click"="go()" attr:"@keyup"="z";
+    };
+    check_str(r, r#"<div on:x="y" x-on:click="go()" @keyup="z"></div>"#);
+}
+
+#[test]
+fn event_capture_and_passive_modifiers() {
+    let scrolled = RwSignal::new(0);
+    let r = mview! {
+        div on:click:capture={|_| ()} {
+            div.scroller on:scroll:passive={move |_| scrolled.update(|n| *n += 1)} {
+                "content"
+            }
+        }
+    };
+    // listeners are not rendered on the server, the element is unchanged.
+    check_str(r, r#"<div><div class="scroller">content</div></div>"#);
+}
+
+#[test]
+fn transition_and_animation_events() {
+    let r = mview! {
+        div.fade
+            on:transitionstart={|ev: leptos::ev::TransitionEvent| _ = ev.property_name()}
+            on:transitionend={|ev: leptos::ev::TransitionEvent| _ = ev.elapsed_time()}
+            on:animationend={|ev: leptos::ev::AnimationEvent| _ = ev.animation_name()}
+            on:animationiteration={|ev: leptos::ev::AnimationEvent| _ = ev.elapsed_time()}
+        {
+            "fading"
+        }
+    };
+    check_str(r, r#"<div class="fade">fading</div>"#);
+}
+
+#[test]
+fn async_event_handler() {
+    async fn save(_value: String) {}
+
+    let value = RwSignal::new(String::new());
+    let r = mview! {
+        form {
+            input on:input:value={move |v| value.set(v)};
+            button on:click:async={move |_ev: leptos::ev::MouseEvent| async move {
+                save(value.get_untracked()).await;
+            }} ("save")
+        }
+    };
+    // the handler is only spawned when the event fires on the client.
+    check_str(r, "<form><input><button>save</button></form>");
+}
+
+#[test]
+fn lazy_attribute() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let computed = Arc::new(AtomicUsize::new(0));
+    let expensive = {
+        let computed = Arc::clone(&computed);
+        move || {
+            computed.fetch_add(1, Ordering::Relaxed);
+            "computed".to_string()
+        }
+    };
+    let r = mview! {
+        div title=lazy{expensive()} data-static="yes" ("content")
+    };
+
+    // effects never run on the server
+    check_str(r, r#"<div data-static="yes">content</div>"#);
+    assert_eq!(computed.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn controlled_value_shorthand() {
+    let value = RwSignal::new("typed".to_string());
+    // `{value}` sets the `value` property, which is not rendered on the server
+    let r = mview! {
+        input type="text" {value} on:input={move |ev| value.set(event_target_value(&ev))};
+        textarea {value};
+    };
+    check_str(r, r#"<input type="text"><textarea></textarea>"#);
+
+    // `value=...` still sets the attribute
+    let r = mview! {
+        input type="text" value={value};
+    };
+    check_str(r, r#"<input type="text" value="typed">"#);
+
+    // the shorthand on other elements is still an attribute
+    let r = mview! {
+        li {value};
+    };
+    check_str(r, r#"<li value="typed">"#);
+}
+
+#[test]
+fn optional_class() {
+    let extra = |class: Option<&'static str>| mview! { div.base class=?{class}; };
+    check_str(extra(Some("extra")), r#"<div class="extra base">"#);
+    check_str(extra(None), r#"<div class="base">"#);
+
+    let title: Option<&str> = None;
+    let active = RwSignal::new(true);
+    let r = mview! {
+        span.tag title=?{title} data-active=?[active.get().then_some("yes")];
+    };
+    check_str(r, r#"<span data-active="yes" class="tag">"#);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn prop_shorthand() {
+    let indeterminate = true;
+    let innerHTML = "<b>bold</b>";
+    let text_content = "text";
+    let r = mview! {
+        input type="checkbox" prop:{indeterminate};
+        div prop:{innerHTML};
+        span prop:{text-content};
+    };
+    // properties aren't rendered in SSR
+    check_str(r, r#"<input type="checkbox"><div></div><span></span>"#);
+}
+
+#[test]
+fn prop_field_paths() {
+    struct Form {
+        name: RwSignal<String>,
+        state: State,
+    }
+    struct State {
+        checked: bool,
+    }
+
+    impl Form {
+        fn view(&self) -> impl IntoView + use<> {
+            mview! {
+                input type="checkbox" prop:checked={self.state.checked};
+                input type="text" prop:value={self.name};
+            }
+        }
+    }
+
+    let form = Form {
+        name: RwSignal::new("Ferris".to_string()),
+        state: State { checked: true },
+    };
+    check_str(form.view(), r#"<input type="checkbox"><input type="text">"#);
+
+    // brackets and other expressions work the same
+    let name = form.name;
+    let r = mview! {
+        input prop:value=[name.get().to_uppercase()] prop:placeholder={form.state.checked.to_string()};
+    };
+    check_str(r, "<input>");
+}
+
+#[test]
+fn undelegated_event() {
+    fn handler(_: leptos::ev::MouseEvent) {}
+    fn same_type<T>(_: &T, _: &T) {}
+
+    // custom events are always added directly to the element
+    let r = mview! { button on:click:undelegated={handler}; };
+    let expected = leptos::html::button().on(
+        leptos::ev::Custom::<leptos::ev::MouseEvent>::new("click"),
+        handler,
+    );
+    same_type(&r, &expected);
+    check_str(r, "<button></button>");
+
+    // the handler still gets the typed event
+    let r = mview! {
+        div on:dblclick:undelegated={|ev| _ = ev.client_x()};
+    };
+    check_str(r, "<div></div>");
+}
+
+#[test]
+fn fn_mut_brackets() {
+    // brackets are `move ||` closures, which leptos accepts as `FnMut`, so
+    // they can mutate their captured state
+    let mut renders = 0;
+    let mut title_renders = 0;
+    let r = mview! {
+        p title=[{ title_renders += 1; format!("rendered {title_renders}") }] (
+            [{ renders += 1; renders }]
+        )
+    };
+    check_str(r, r#"<p title="rendered 1">1</p>"#);
+}
+
+#[test]
+fn boolean_attributes() {
+    // there is no list of boolean attributes, any attribute without a value is
+    // set to `true`
+    let r = mview! {
+        div inert hidden {
+            input autofocus;
+            button type="submit" formnovalidate;
+            dialog open;
+        }
+    };
+    check_str(
+        r,
+        r#"<div inert hidden><input autofocus><button type="submit" formnovalidate></button><dialog open></dialog></div>"#,
+    );
+
+    let inert = RwSignal::new(false);
+    let r = mview! {
+        div inert=[inert.get()] {
+            button formnovalidate=false;
+        }
+    };
+    check_str(r, "<div><button></button></div>");
+}
+
+#[test]
+fn boolean_data_attributes() {
+    // `data-*` attributes are not stringified, same as other attributes
+    let active = RwSignal::new(false);
+    let r = move || {
+        mview! {
+            div
+                data-active=[active.get()]
+                data-state=[active.get().to_string()]
+                data-never=false;
+        }
+    };
+    check_str(r(), r#"<div data-state="false"></div>"#);
+
+    active.set(true);
+    check_str(r(), r#"<div data-active data-state="true"></div>"#);
+}
+
+#[test]
+fn keyword_boolean_attributes() {
+    // `contenteditable`, `draggable` and `spellcheck` take "true" and "false",
+    // so literal booleans are stringified instead of removing the attribute
+    let r = mview! {
+        div contenteditable=true {
+            p contenteditable spellcheck=false("text")
+            img draggable=false;
+        }
+    };
+    check_str(
+        r,
+        r#"<div contenteditable="true"><p contenteditable="true" spellcheck="false">text</p><img draggable="false"></div>"#,
+    );
+
+    let editable = RwSignal::new(false);
+    let r = mview! { div contenteditable=[editable.get().to_string()]; };
+    check_str(r, r#"<div contenteditable="false"></div>"#);
+}
+
+#[test]
+fn global_listeners() {
+    // events can't be fired in tests and effects don't run on the server, so
+    // this only checks that the handlers are typed and the view still renders
+    let pressed = RwSignal::new(String::new());
+    let r = mview! {
+        on:keydown={move |ev| pressed.set(ev.key())};
+        on:resize={|_| ()};
+        p("pressed: " {pressed})
+    };
+    check_str(r, "<p>pressed: ");
+}
+
+#[test]
+fn repeated_literals() {
+    // repeated literals are shared in a `const`, each use still renders the
+    // same
+    let r = mview! {
+        ul.menu {
+            li.item class="active" title="menu item" ("one")
+            li.item class="active" title="menu item" ("two")
+            li.item class="active" title="menu item" ("three")
+        }
+    };
+    check_str(
+        r,
+        r#"<ul class="menu"><li title="menu item" class="active item">one</li><li title="menu item" class="active item">two</li><li title="menu item" class="active item">three</li></ul>"#,
+    );
+}
+
+/// All values of the attribute in the HTML.
+fn attr_values<'a>(html: &'a str, name: &str) -> Vec<&'a str> {
+    let prefix = format!(r#" {name}=""#);
+    html.match_indices(&prefix)
+        .map(|(i, _)| {
+            let start = i + prefix.len();
+            &html[start..start + html[start..].find('"').unwrap()]
+        })
+        .collect()
+}
+
+#[test]
+fn auto_ids() {
+    #[component]
+    fn Field(label: &'static str) -> impl IntoView {
+        mview! {
+            label #auto(label) ({label})
+            input aria-labelledby=@ref(label) aria-describedby=@ref(hint);
+            p #auto(hint) ("Required")
+        }
+    }
+
+    let html = mview! {
+        Field label="Name";
+        Field label="Email";
+    }
+    .to_html();
+
+    let ids = attr_values(&html, "id");
+    assert_eq!(ids.len(), 4, "{html}");
+    assert!(ids[0].starts_with("mview-label-"), "{html}");
+    assert!(ids[1].starts_with("mview-hint-"), "{html}");
+    assert_eq!(attr_values(&html, "aria-labelledby"), [ids[0], ids[2]]);
+    assert_eq!(attr_values(&html, "aria-describedby"), [ids[1], ids[3]]);
+    // every instance of the component has its own ids
+    assert_ne!(ids[0], ids[2]);
+    assert_ne!(ids[1], ids[3]);
+}
+
+#[test]
+fn auto_ids_in_loops() {
+    let html = mview! {
+        ul {
+            for {name} in {["a", "b"]} (
+                li aria-labelledby=@ref(name) ( span #auto(name) ({name}) )
+            )
+        }
+    }
+    .to_html();
+
+    let ids = attr_values(&html, "id");
+    assert_eq!(attr_values(&html, "aria-labelledby"), ids);
+    assert_eq!(ids.len(), 2, "{html}");
+    assert_ne!(ids[0], ids[1]);
+}
+
+#[test]
+fn auto_ids_across_loops() {
+    // ids outside of the loop are the same for every item
+    let html = Owner::new().with(|| {
+        mview! {
+            section #auto(title) {
+                h2 #auto(heading) ("Items")
+                for {name} in {["a", "b"]} (
+                    li aria-labelledby=@ref(title) aria-describedby=@ref(heading) ({name})
+                )
+                for {name} in [vec!["c"]] key={|name| *name} (
+                    li aria-labelledby=@ref(title) ({name})
+                )
+            }
+        }
+        .to_html()
+    });
+
+    let ids = attr_values(&html, "id");
+    assert_eq!(ids.len(), 2, "{html}");
+    assert_eq!(attr_values(&html, "aria-labelledby"), [ids[0]; 3]);
+    assert_eq!(attr_values(&html, "aria-describedby"), [ids[1]; 2]);
+}
@@ -18,6 +18,19 @@ fn html_child() {
     )
 }
 
+#[test]
+fn kebab_selector_root() {
+    // should not be mistaken for `footer.site - footer(...)`
+    let res = mview! {
+        footer.site-footer(p.text-small("hi"))
+    };
+
+    check_str(
+        res,
+        r#"<footer class="site-footer"><p class="text-small">hi</p></footer>"#,
+    )
+}
+
 #[test]
 fn component_closure() {
     if false {
@@ -243,3 +243,50 @@ fn clone_in_slot() {
         }
     };
 }
+
+#[component]
+fn MaybeFallback(
+    #[prop(into)] cond: Signal<bool>,
+    then: Then,
+    #[prop(optional_no_strip)] fallback: Option<Fallback>,
+) -> impl IntoView {
+    move || {
+        if cond.get() {
+            Either::Left((then.children)().into_view())
+        } else if let Some(fallback) = &fallback {
+            Either::Left((fallback.children)().into_view())
+        } else {
+            Either::Right(().into_view())
+        }
+    }
+}
+
+#[test]
+fn conditional_slots() {
+    for show_fallback in [true, false] {
+        let r = mview! {
+            div {
+                MaybeFallback cond=false {
+                    slot:Then { "no" }
+                    slot:Fallback when={show_fallback} { "fallback" }
+                }
+            }
+        };
+        let expected = if show_fallback { "fallback" } else { "</div>" };
+        check_str(r, expected);
+    }
+
+    // multiple slots of the same name are filtered
+    let is_div5 = false;
+    let r = mview! {
+        SlotIf cond=false {
+            slot:Then { "no" }
+            slot:ElseIf cond=true when=[is_div5 && true] { "x5" }
+            slot:ElseIf cond=true { "x7" }
+        }
+    };
+    check_str(
+        r,
+        Contains::AllOfNoneOf([["x7"].as_slice(), ["x5"].as_slice()]),
+    );
+}
@@ -0,0 +1,23 @@
+//! Only runs with the `source-locations` feature, which would change the
+//! output of every other test. Run with:
+//! `cargo test --features source-locations --test source_locations`
+
+#![cfg(feature = "source-locations")]
+
+use leptos::prelude::*;
+use leptos_mview::mview;
+mod utils;
+use utils::check_str;
+
+#[test]
+fn element_locations() {
+    let r = mview! {
+        div.outer {
+            span("hi")
+        }
+    };
+    check_str(
+        r,
+        r#"<div data-mview-loc="tests/source_locations.rs:15" class="outer"><span data-mview-loc="tests/source_locations.rs:16">hi</span></div>"#,
+    );
+}
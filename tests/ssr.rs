@@ -0,0 +1,118 @@
+//! `mview_ssr!` should render exactly the same HTML as `mview!` on the server.
+
+use leptos::prelude::*;
+use leptos_mview::{mview, mview_ssr};
+
+#[test]
+fn static_matches() {
+    let ssr = mview_ssr! {
+        header.site #top data-kind="main" {
+            h1("Fish & chips")
+            "some " "adjacent " "text"
+            input type="checkbox" checked=true;
+            a href="/?a=1&b=\"2\"" style="color: red" ("link")
+        }
+        "root text"
+    };
+    let view = mview! {
+        header.site #top data-kind="main" {
+            h1("Fish & chips")
+            "some " "adjacent " "text"
+            input type="checkbox" checked=true;
+            a href="/?a=1&b=\"2\"" style="color: red" ("link")
+        }
+        "root text"
+    };
+
+    assert_eq!(ssr, view.to_html());
+}
+
+#[test]
+fn dynamic_matches() {
+    let count = RwSignal::new(3);
+    let ssr = mview_ssr! {
+        div.counter {
+            "count: " {count} " (" f["{}", count.get() * 2] ")"
+            span.static("static")
+        }
+    };
+    let view = mview! {
+        div.counter {
+            "count: " {count} " (" f["{}", count.get() * 2] ")"
+            span.static("static")
+        }
+    };
+
+    assert_eq!(ssr, view.to_html());
+}
+
+#[test]
+fn empty() {
+    let ssr: String = mview_ssr! {};
+    assert_eq!(ssr, "");
+}
+
+#[test]
+fn raw_and_multiline_strings() {
+    let ssr = mview_ssr! {
+        pre(r#"{"name": "mview", "tags": ["a", "b"]}"#)
+        style(r"p { content: '\n'; }")
+        p("a long
+            paragraph over \
+            multiple lines")
+    };
+    let view = mview! {
+        pre(r#"{"name": "mview", "tags": ["a", "b"]}"#)
+        style(r"p { content: '\n'; }")
+        p("a long
+            paragraph over \
+            multiple lines")
+    };
+
+    assert_eq!(ssr, view.to_html());
+    assert!(ssr.starts_with(r#"<pre>{"name": "mview", "tags": ["a", "b"]}</pre>"#));
+    assert!(ssr.ends_with("<p>a long\n            paragraph over multiple lines</p>"));
+}
+
+#[test]
+fn whitespace_keywords() {
+    let ssr = mview_ssr! {
+        p(strong("Name:") space em("mview") nbsp "0.4")
+    };
+    let view = mview! {
+        p(strong("Name:") space em("mview") nbsp "0.4")
+    };
+
+    assert_eq!(ssr, view.to_html());
+    assert_eq!(
+        ssr,
+        "<p><strong>Name:</strong> <em>mview</em>\u{a0}<!>0.4</p>"
+    );
+}
+
+#[cfg(feature = "trim-whitespace")]
+#[test]
+fn trim_whitespace() {
+    let ssr = mview_ssr! {
+        ul {
+            "  "
+            li("a") "\n    " li(" b ")
+            ""
+        }
+        p(strong("x") " " em("y") space "\u{a0}" "z")
+    };
+    let view = mview! {
+        ul {
+            "  "
+            li("a") "\n    " li(" b ")
+            ""
+        }
+        p(strong("x") " " em("y") space "\u{a0}" "z")
+    };
+
+    assert_eq!(ssr, view.to_html());
+    assert_eq!(
+        ssr,
+        "<ul><li>a</li><li> b </li></ul><p><strong>x</strong><em>y</em> <!>\u{a0}<!>z</p>"
+    );
+}
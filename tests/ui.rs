@@ -5,9 +5,27 @@ fn ui() {
     // these trait errors span the entire macro output,
     // so there is no good way to scope errors to a specific span.
     //
-    // not running any UI tests for now.
+    // not running most UI tests for now.
 
     // let t = trybuild::TestCases::new();
     // t.pass("tests/ui/pass/*.rs");
     // t.compile_fail("tests/ui/errors/*.rs");
 }
+
+/// UI tests for errors that are scoped well enough to be checked.
+///
+/// Regenerate the `.stderr` files with `TRYBUILD=overwrite`.
+#[test]
+fn ui_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/errors/multiple_errors.rs");
+    t.compile_fail("tests/ui/errors/unwrapped_expr_child.rs");
+    t.compile_fail("tests/ui/errors/com_missing_prop.rs");
+    #[cfg(feature = "strict")]
+    t.compile_fail("tests/ui/errors/enumerated_attr_values.rs");
+    t.compile_fail("tests/ui/errors/bind_non_form_control.rs");
+    t.compile_fail("tests/ui/errors/children_attr_and_block.rs");
+    t.compile_fail("tests/ui/errors/positional_args.rs");
+    t.compile_fail("tests/ui/errors/rust_match_trailing.rs");
+    t.compile_fail("tests/ui/errors/auto_ids.rs");
+}
@@ -0,0 +1,24 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+fn main() {
+    // missing the `#auto(label)`
+    _ = mview! {
+        input aria-labelledby=@ref(label);
+        span #auto(lable) ("Name")
+    };
+
+    _ = mview! {
+        h2 #auto(title) ("a")
+        h3 #auto(title) ("b")
+        p #auto(a) #auto(b) ("c")
+        p #id #auto(c) ("d")
+    };
+
+    // ids in a loop are new for every item
+    _ = mview! {
+        ul aria-activedescendant=@ref(item) {
+            for {n} in {0..3} ( li #auto(item) ({n}) )
+        }
+    };
+}
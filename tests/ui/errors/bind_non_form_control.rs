@@ -0,0 +1,19 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+fn main() {
+    let value = RwSignal::new(String::new());
+    _ = mview! {
+        div bind:value={value};
+    };
+    _ = mview! {
+        p bind:checked={RwSignal::new(false)};
+    };
+    // form controls and custom elements are fine
+    _ = mview! {
+        input bind:value={value};
+        textarea bind:value={value};
+        select bind:value={value};
+        my-input bind:value={value};
+    };
+}
@@ -0,0 +1,14 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+#[component]
+fn Counter(#[prop(optional)] count: i32, #[prop(optional)] show: bool) -> impl IntoView {
+    _ = (count, show);
+}
+
+fn main() {
+    // `count` is not a bool, so it needs a value
+    _ = mview! {
+        Counter count show;
+    };
+}
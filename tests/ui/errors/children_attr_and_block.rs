@@ -0,0 +1,14 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+#[component]
+fn Wrapper(children: Children) -> impl IntoView {
+    mview! { div(children) }
+}
+
+fn main() {
+    let content = || "hi";
+    _ = mview! {
+        Wrapper children={content} ("more")
+    };
+}
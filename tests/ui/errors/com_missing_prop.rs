@@ -0,0 +1,19 @@
+//! A missing required prop should only produce errors at the component tag,
+//! not the whole macro.
+
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+#[component]
+fn Thing(label: &'static str, count: i32) -> impl IntoView {
+    _ = count;
+    label
+}
+
+fn main() {
+    _ = mview! {
+        div {
+            Thing label="a";
+        }
+    };
+}
@@ -0,0 +1,20 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+struct State {
+    value: String,
+    active: bool,
+}
+
+fn main() {
+    let state = State {
+        value: String::new(),
+        active: true,
+    };
+    _ = mview! {
+        input prop:{state.value};
+    };
+    _ = mview! {
+        div class:{state.active};
+    };
+}
@@ -0,0 +1,15 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+fn main() {
+    _ = mview! {
+        img src="/a.png" loading="lazyy" decoding="async";
+    };
+
+    _ = mview! {
+        div {
+            p dir="left" ("text")
+            script crossorigin="anonymous" src="/a.js" ("")
+        }
+    };
+}
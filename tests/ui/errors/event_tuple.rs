@@ -0,0 +1,12 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+fn main() {
+    let (_value, set_value) = signal(String::new());
+    _ = mview! {
+        input on:input=(set_value);
+    };
+    _ = mview! {
+        input on:input=(event_target_value, set_value, set_value);
+    };
+}
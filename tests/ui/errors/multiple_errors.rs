@@ -0,0 +1,14 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+fn main() {
+    // all of these errors should be reported, not just the first one.
+    _ = mview! {
+        div {
+            + span("a")
+            p. ("text")
+            em { - "b" }
+            strong("ok")
+        }
+    };
+}
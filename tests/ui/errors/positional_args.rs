@@ -0,0 +1,14 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+#[component]
+fn Pair(#[prop(optional)] a: i32, #[prop(optional)] b: i32) -> impl IntoView {
+    a + b
+}
+
+fn main() {
+    _ = mview! { Pair(1, 2) };
+    // still an error with blocks
+    let x = 1;
+    _ = mview! { Pair({x}, {x}) };
+}
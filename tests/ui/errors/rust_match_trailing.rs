@@ -0,0 +1,13 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+fn main() {
+    let count = Some(1);
+    _ = mview! {
+        match count {
+            Some(n) => n.into_any(),
+            None => "none".into_any(),
+        }
+        p("after")
+    };
+}
@@ -0,0 +1,18 @@
+use leptos::prelude::*;
+use leptos_mview::mview;
+
+#[component]
+fn Wrapper(children: Children) -> impl IntoView {
+    mview! { div(children) }
+}
+
+fn main() {
+    let count = 1;
+    _ = mview! {
+        Wrapper { count + 1 }
+    };
+
+    _ = mview! {
+        p(format!("{count} items"))
+    };
+}
@@ -19,3 +19,138 @@ fn f_value() {
     };
     check_str(r, r#"<input type="number" value="2.13""#);
 }
+
+#[test]
+fn p_value() {
+    let count = RwSignal::new(3);
+    // logs whenever the closure is rerun, and renders nothing
+    let r = mview! {
+        div {
+            "count: " {count}
+            p["rendering count {}", count.get()]
+        }
+    };
+
+    check_str(r, "<div>count: <!>3<!></div>");
+
+    // in an event handler, the event is ignored
+    let r = mview! {
+        button on:click=p["clicked with count {}", count.get()] ("log")
+    };
+    check_str(r, "<button>log</button>");
+}
+
+#[test]
+fn classes_value() {
+    let active = RwSignal::new(true);
+    let size = "large";
+    let r = mview! {
+        button class=classes["btn", active => active.get(), "is-disabled" => false, size] ("ok")
+    };
+    check_str(r, r#"<button class="btn active large">ok</button>"#);
+
+    active.set(false);
+    let r = mview! {
+        div class=classes[
+            "card shadow",
+            is-active => active.get(),
+            text-muted => !active.get(),
+        ];
+    };
+    check_str(r, r#"<div class="card shadow text-muted">"#);
+}
+
+#[test]
+fn style_value() {
+    let color = RwSignal::new("red");
+    let width = 120;
+    let r = mview! {
+        div style=s["display: flex", color: color.get(), width: format!("{width}px")];
+    };
+    check_str(
+        r,
+        r#"<div style="display: flex; color: red; width: 120px;">"#,
+    );
+
+    let r = mview! {
+        div style=s[--gap: 4, "margin-top": "1em"];
+    };
+    check_str(r, r#"<div style="--gap: 4; margin-top: 1em;">"#);
+}
+
+#[test]
+fn multi_statement_bracket() {
+    let count = RwSignal::new(3);
+    let r = mview! {
+        div
+            data-block=[{ let doubled = count.get() * 2; doubled + 1 }]
+            data-stmts=[let doubled = count.get() * 2; doubled + 2]
+        {
+            [let tripled = count.get() * 3; tripled]
+        }
+    };
+
+    check_str(r, r#"<div data-block="7" data-stmts="8">9</div>"#);
+}
+
+#[test]
+fn inline_if() {
+    let active = RwSignal::new(true);
+    let view = move || {
+        mview! {
+            div
+                class=if active.get() { "on" } else { "off" }
+                data-state=if !active.get() { "idle" } else if true { "busy" } else { "" }
+            {
+                if active.get() { "yes" } else { "no" }
+            }
+        }
+    };
+
+    check_str(view(), r#"<div data-state="busy" class="on">yes</div>"#);
+    active.set(false);
+    check_str(view(), r#"<div data-state="idle" class="off">no</div>"#);
+}
+
+#[test]
+fn negated_values() {
+    let visible = RwSignal::new(true);
+    let is_visible = move || visible.get();
+    let view = move || {
+        mview! {
+            div hidden=!{is_visible} aria-hidden=![visible.get()] {
+                span hidden=!{visible};
+            }
+        }
+    };
+    check_str(view(), "<div><span></span></div>");
+    visible.set(false);
+    check_str(view(), "<div hidden aria-hidden><span hidden></span></div>");
+}
+
+#[test]
+fn bracket_closure_args() {
+    // events can't be fired in tests, this checks that the handler takes the
+    // event as an argument
+    let clicks = RwSignal::new(0);
+    let r = mview! {
+        button on:click=[ev => ev.prevent_default(); clicks.update(|n| *n += 1)] ("click")
+        button on:click=[_ => clicks.set(0)] ("reset")
+    };
+    check_str(r, "<button>click</button><button>reset</button>");
+
+    #[component]
+    fn Mapped(map: impl Fn(i32) -> i32 + 'static) -> impl IntoView { map(2) }
+
+    check_str(mview! { Mapped map=[n => n * 3]; }, "6");
+    // parentheses around a single pattern are removed, as they would warn
+    #[deny(unused_parens)]
+    let r = mview! { Mapped map=[(n) => n + 1]; };
+    check_str(r, "3");
+
+    #[component]
+    fn Paired(map: impl Fn((i32, i32)) -> i32 + 'static) -> impl IntoView { map((2, 3)) }
+
+    // a tuple pattern keeps its parentheses
+    check_str(mview! { Paired map=[(a, b) => a * b]; }, "6");
+}